@@ -0,0 +1,119 @@
+use std::{fs::File, io::Write, path::Path};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use fibonacci::{
+    fibo::{fib1, fib2, fib3},
+    prover::{prove, setup, verify},
+    range_check::{example1, example2},
+};
+use halo2_proofs::{
+    circuit::Value,
+    halo2curves::pasta::Fp,
+    plonk::{keygen_pk, keygen_vk, Circuit},
+};
+use rand_core::OsRng;
+
+// Timings end up in criterion's own `raw.csv` files (the `csv_output` feature), and proof sizes,
+// which don't change between runs, in `PROOF_SIZE_CSV`.
+const PROOF_SIZE_CSV: &str = "target/criterion/proof_size.csv";
+
+// `BENCH_MAX_K` caps the Fibonacci sweep, which takes a while at the upper end.
+fn fibo_ks() -> std::ops::RangeInclusive<u32> {
+    let max_k = std::env::var("BENCH_MAX_K")
+        .ok()
+        .and_then(|k| k.parse().ok())
+        .unwrap_or(18);
+    8..=max_k
+}
+
+fn bench_circuit<C: Circuit<Fp>>(
+    c: &mut Criterion,
+    csv: &mut File,
+    name: &str,
+    k: u32,
+    circuit: impl Fn() -> C,
+    instances: &[&[Fp]],
+) {
+    let params = setup(k);
+
+    let mut group = c.benchmark_group(format!("{}/k={}", name, k));
+    group.sample_size(10);
+
+    group.bench_function("keygen_vk", |b| {
+        b.iter(|| keygen_vk(&params, &circuit().without_witnesses()).unwrap())
+    });
+    let vk = keygen_vk(&params, &circuit().without_witnesses()).unwrap();
+
+    group.bench_function("keygen_pk", |b| {
+        b.iter(|| keygen_pk(&params, vk.clone(), &circuit().without_witnesses()).unwrap())
+    });
+    let pk = keygen_pk(&params, vk, &circuit().without_witnesses()).unwrap();
+
+    group.bench_function("create_proof", |b| {
+        b.iter(|| prove(&params, &pk, circuit(), instances, OsRng).unwrap())
+    });
+    let proof = prove(&params, &pk, circuit(), instances, OsRng).unwrap();
+
+    group.bench_function("verify_proof", |b| {
+        b.iter(|| verify(&params, pk.get_vk(), &proof, instances).unwrap())
+    });
+
+    group.finish();
+
+    writeln!(csv, "{},{},{}", name, k, proof.len()).unwrap();
+}
+
+fn benches(c: &mut Criterion) {
+    let path = Path::new(PROOF_SIZE_CSV);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let mut csv = File::create(path).unwrap();
+    writeln!(csv, "circuit,k,proof_bytes").unwrap();
+
+    let (a, b) = (Value::known(Fp::from(0)), Value::known(Fp::from(1)));
+    for k in fibo_ks() {
+        let circuit = || fib1::FiboCircuit::new(a, b);
+        bench_circuit(c, &mut csv, "fib1", k, circuit, &[&[Fp::from(89)]]);
+
+        let circuit = || fib2::FiboCircuit::new(a, b);
+        bench_circuit(c, &mut csv, "fib2", k, circuit, &[&[Fp::from(89)]]);
+
+        let circuit = || fib3::FiboCircuit::new(a, b);
+        bench_circuit(c, &mut csv, "fib3", k, circuit, &[&[Fp::from(34)]]);
+    }
+
+    // The expression check has degree `RANGE`, so it stays small.
+    macro_rules! range_check1 {
+        ($range:expr, $k:expr) => {
+            let circuit = || {
+                example1::RangeCheckCircuit::<Fp, $range>::new(Value::known(Fp::from(
+                    $range as u64 - 1,
+                )))
+            };
+            let name = format!("range_check1/RANGE={}", $range);
+            bench_circuit(c, &mut csv, &name, $k, circuit, &[]);
+        };
+    }
+    range_check1!(4, 8);
+    range_check1!(8, 8);
+    range_check1!(16, 8);
+
+    // Switches from the expression to the lookup above `RANGE = 256`, and the table needs
+    // `RANGE` rows. The expression is configured either way, so its degree bounds `RANGE`.
+    macro_rules! range_check2 {
+        ($range:expr, $k:expr) => {
+            let circuit = || {
+                example2::RangeCheckCircuit::<Fp, $range>::new(Value::known(Fp::from(
+                    $range as u64 - 1,
+                )))
+            };
+            let name = format!("range_check2/RANGE={}", $range);
+            bench_circuit(c, &mut csv, &name, $k, circuit, &[]);
+        };
+    }
+    range_check2!(16, 8);
+    range_check2!(256, 9);
+    range_check2!(257, 9);
+}
+
+criterion_group!(proving, benches);
+criterion_main!(proving);
@@ -0,0 +1,235 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::Value,
+    halo2curves::pasta::{EqAffine, Fp},
+    plonk::{self, verify_proof, ProvingKey, VerifyingKey},
+    poly::{
+        commitment::MSM,
+        ipa::{
+            commitment::IPACommitmentScheme,
+            msm::MSMIPA,
+            multiopen::VerifierIPA,
+            strategy::{Accumulator, GuardIPA},
+        },
+        VerificationStrategy,
+    },
+    transcript::{Blake2bRead, Challenge255, TranscriptReadBuffer},
+};
+use rand_core::{OsRng, RngCore};
+
+use crate::{
+    fibo::{fib1::FiboCircuit, fibonacci},
+    prover::{keygen, prove, setup, Params},
+};
+
+// fib1 fits in `2^4` rows.
+pub const K: u32 = 4;
+
+// Proofs of fib1's `FiboCircuit` with their public outputs, and the accumulated IPA openings of
+// the proofs. Each accumulator is the claim `G = <s(u), params.g>` for the challenges `u` of an
+// opening, computed by the aggregator so the verifier only checks the openings up to `G` and then
+// all the claims at once in `decide`. The proofs themselves are kept: dropping them would need a
+// verifier circuit on the other curve of the cycle to vouch for the accumulators.
+pub struct Aggregate {
+    pub outputs: Vec<Fp>,
+    pub proofs: Vec<Vec<u8>>,
+    pub accumulators: Vec<Accumulator<EqAffine>>,
+}
+
+pub struct Aggregator {
+    params: Params,
+    pk: ProvingKey<EqAffine>,
+}
+
+impl Aggregator {
+    pub fn new() -> Result<Self, plonk::Error> {
+        let params = setup(K);
+        let pk = keygen(
+            &params,
+            &FiboCircuit::new(Value::unknown(), Value::unknown()),
+        )?;
+
+        Ok(Self { params, pk })
+    }
+
+    // One proof per `(a, b)`, each exposing the 11th term of the sequence starting with `a, b`.
+    pub fn aggregate(
+        &self,
+        inputs: &[(Fp, Fp)],
+        mut rng: impl RngCore,
+    ) -> Result<Aggregate, plonk::Error> {
+        let mut outputs = vec![];
+        let mut proofs = vec![];
+        for (a, b) in inputs.iter() {
+            let output = fibonacci(*a, *b, 11);
+            let circuit = FiboCircuit::new(Value::known(*a), Value::known(*b));
+            proofs.push(prove(
+                &self.params,
+                &self.pk,
+                circuit,
+                &[&[output]],
+                &mut rng,
+            )?);
+            outputs.push(output);
+        }
+
+        let accumulators = open(&self.params, self.pk.get_vk(), &outputs, &proofs, |_| None)?;
+
+        Ok(Aggregate {
+            outputs,
+            proofs,
+            accumulators,
+        })
+    }
+
+    pub fn verifier(&self) -> AggregateVerifier<'_> {
+        AggregateVerifier::new(&self.params, self.pk.get_vk())
+    }
+}
+
+pub struct AggregateVerifier<'a> {
+    params: &'a Params,
+    vk: &'a VerifyingKey<EqAffine>,
+}
+
+impl<'a> AggregateVerifier<'a> {
+    // `params` and `vk` are those of fib1's `FiboCircuit` at `K`, which a verifier can generate
+    // without the aggregator's keys.
+    pub fn new(params: &'a Params, vk: &'a VerifyingKey<EqAffine>) -> Self {
+        Self { params, vk }
+    }
+
+    // The proofs are checked up to their openings, taking `G` from the accumulators, whose
+    // challenges must be those of the proofs. The claims are then checked with `decide`.
+    pub fn verify(&self, aggregate: &Aggregate) -> Result<(), plonk::Error> {
+        if aggregate.outputs.len() != aggregate.proofs.len()
+            || aggregate.accumulators.len() != aggregate.proofs.len()
+        {
+            return Err(plonk::Error::InvalidInstances);
+        }
+
+        let accumulators = open(
+            self.params,
+            self.vk,
+            &aggregate.outputs,
+            &aggregate.proofs,
+            |i| Some(aggregate.accumulators[i].g),
+        )?;
+        let challenges_match = accumulators
+            .iter()
+            .zip(aggregate.accumulators.iter())
+            .all(|(opened, claimed)| opened.u_packed == claimed.u_packed);
+
+        if challenges_match && decide(self.params, &aggregate.accumulators) {
+            Ok(())
+        } else {
+            Err(plonk::Error::ConstraintSystemFailure)
+        }
+    }
+}
+
+// The deferred check of `accumulators`, with one MSM over `params.g` for a random linear
+// combination of their claims `G = <s(u), params.g>`.
+pub fn decide(params: &Params, accumulators: &[Accumulator<EqAffine>]) -> bool {
+    let mut msm = MSMIPA::new(params);
+    for accumulator in accumulators {
+        let r = Fp::random(OsRng);
+        msm.add_to_g_scalars(&compute_s(&accumulator.u_packed, r));
+        msm.append_term(-r, accumulator.g.into());
+    }
+
+    msm.check()
+}
+
+// Checks the proofs up to their IPA openings, with `G` for the `i`th proof from `g(i)` or, if
+// `None`, computed over `params.g`. The rest of the openings is checked with one MSM, and the
+// claims about `G` are returned.
+fn open(
+    params: &Params,
+    vk: &VerifyingKey<EqAffine>,
+    outputs: &[Fp],
+    proofs: &[Vec<u8>],
+    g: impl Fn(usize) -> Option<EqAffine>,
+) -> Result<Vec<Accumulator<EqAffine>>, plonk::Error> {
+    let mut strategy = DeferredStrategy::new(params);
+    for (i, (output, proof)) in outputs.iter().zip(proofs.iter()).enumerate() {
+        strategy.g = g(i);
+
+        let mut transcript = Blake2bRead::<_, EqAffine, Challenge255<_>>::init(&proof[..]);
+        strategy = verify_proof::<IPACommitmentScheme<EqAffine>, VerifierIPA<'_, EqAffine>, _, _, _>(
+            params,
+            vk,
+            strategy,
+            &[&[&[*output]]],
+            &mut transcript,
+        )?;
+    }
+
+    let accumulators = strategy.accumulators.clone();
+    if strategy.finalize() {
+        Ok(accumulators)
+    } else {
+        Err(plonk::Error::ConstraintSystemFailure)
+    }
+}
+
+// `AccumulatorStrategy`, but `G` is appended to the MSM and its claim kept, instead of adding
+// `s(u)` to the scalars of `params.g`.
+struct DeferredStrategy<'params> {
+    msm: MSMIPA<'params, EqAffine>,
+    g: Option<EqAffine>,
+    accumulators: Vec<Accumulator<EqAffine>>,
+}
+
+impl<'params>
+    VerificationStrategy<'params, IPACommitmentScheme<EqAffine>, VerifierIPA<'params, EqAffine>>
+    for DeferredStrategy<'params>
+{
+    type Output = Self;
+
+    fn new(params: &'params Params) -> Self {
+        Self {
+            msm: MSMIPA::new(params),
+            g: None,
+            accumulators: vec![],
+        }
+    }
+
+    fn process(
+        mut self,
+        f: impl FnOnce(MSMIPA<'params, EqAffine>) -> Result<GuardIPA<'params, EqAffine>, plonk::Error>,
+    ) -> Result<Self::Output, plonk::Error> {
+        self.msm.scale(Fp::random(OsRng));
+        let guard = f(self.msm)?;
+
+        let g = self.g.unwrap_or_else(|| guard.compute_g());
+        let (msm, accumulator) = guard.use_g(g);
+        self.accumulators.push(accumulator);
+
+        Ok(Self {
+            msm,
+            g: None,
+            accumulators: self.accumulators,
+        })
+    }
+
+    fn finalize(self) -> bool {
+        self.msm.check()
+    }
+}
+
+// The coefficients of `prod_i (1 + u_{k - 1 - i} X^{2^i})`, times `init`, as halo2 computes them
+// for `G`. With `Challenge255` the packed challenges are the challenges themselves.
+fn compute_s(u: &[Fp], init: Fp) -> Vec<Fp> {
+    let mut s = vec![Fp::ZERO; 1 << u.len()];
+    s[0] = init;
+
+    for (i, u_j) in u.iter().rev().enumerate() {
+        let (left, right) = s.split_at_mut(1 << i);
+        for (l, r) in left.iter().zip(right.iter_mut()) {
+            *r = *l * u_j;
+        }
+    }
+
+    s
+}
@@ -0,0 +1,38 @@
+use fibonacci::builder::CircuitBuilder;
+use halo2_proofs::{circuit::Value, dev::MockProver, halo2curves::pasta::Fp};
+
+fn main() {
+    // x^3 + x + 5 == out, and 1 / y
+    let mut b = CircuitBuilder::new();
+
+    let x = b.private(Value::known(Fp::from(3)));
+    let y = b.private(Value::known(Fp::from(7)));
+    let five = b.constant(Fp::from(5));
+
+    let x2 = b.mul(x, x);
+    let x3 = b.mul(x2, x);
+    let sum = b.add(x3, x);
+    let out = b.add(sum, five);
+    b.expose(out);
+
+    let y_inv = b.inverse(y);
+    let one = b.mul(y, y_inv);
+    let expected_one = b.constant(Fp::from(1));
+    b.assert_equal(one, expected_one);
+
+    let diff = b.sub(out, y);
+    b.expose(diff);
+
+    let circuit = b.build();
+    let k = circuit.min_k();
+    let public = vec![Fp::from(35), Fp::from(28)];
+    circuit
+        .public_inputs()
+        .assert_if_known(|inputs| *inputs == public);
+
+    let prover = MockProver::run(k, &circuit, vec![public]).unwrap();
+    prover.assert_satisfied();
+
+    let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(36), Fp::from(28)]]).unwrap();
+    assert!(prover.verify().is_err());
+}
@@ -0,0 +1,93 @@
+use ff::PrimeField;
+use fibonacci::div_mod::{DivModChip, DivModConfig};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    halo2curves::pasta::Fp,
+    plonk::{self, Circuit, Column, ConstraintSystem, Instance},
+};
+
+#[derive(Clone)]
+struct DivModCircuitConfig<F> {
+    div_mod_config: DivModConfig<F>,
+    instance: Column<Instance>,
+}
+
+#[derive(Default)]
+struct DivModCircuit<F> {
+    a: Value<F>,
+    d: Value<F>,
+}
+
+impl<F: PrimeField> Circuit<F> for DivModCircuit<F> {
+    type Config = DivModCircuitConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let div_mod_config = DivModChip::configure(meta);
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        DivModCircuitConfig {
+            div_mod_config,
+            instance,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let chip = DivModChip::new(config.div_mod_config);
+
+        chip.load_table(layouter.namespace(|| "load table"))?;
+
+        let a = chip.load_private(layouter.namespace(|| "load a"), self.a)?;
+        let d = chip.load_private(layouter.namespace(|| "load d"), self.d)?;
+
+        let (q, r) = chip.div_mod(layouter.namespace(|| "div mod"), &a, &d)?;
+
+        layouter.constrain_instance(q.cell(), config.instance, 0)?;
+        layouter.constrain_instance(r.cell(), config.instance, 1)
+    }
+}
+
+fn run(a: u64, d: u64, q: u64, r: u64) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let k = 9;
+    let circuit = DivModCircuit {
+        a: Value::known(Fp::from(a)),
+        d: Value::known(Fp::from(d)),
+    };
+
+    let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(q), Fp::from(r)]]).unwrap();
+    prover.verify()
+}
+
+fn main() {
+    // division by 1
+    for a in [0, 1, 7, u64::MAX] {
+        run(a, 1, a, 0).unwrap();
+    }
+
+    // division by powers of two
+    let a = 0xdead_beef_cafe_babe;
+    for i in 0..64 {
+        let d = 1u64 << i;
+        run(a, d, a >> i, a & (d - 1)).unwrap();
+    }
+
+    run(100, 7, 14, 2).unwrap();
+    run(u64::MAX, u64::MAX, 1, 0).unwrap();
+
+    // wrong remainder or quotient
+    assert!(run(100, 7, 13, 9).is_err());
+    assert!(run(100, 7, 14, 3).is_err());
+
+    // division by zero
+    assert!(run(100, 0, 0, 100).is_err());
+}
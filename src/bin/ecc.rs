@@ -0,0 +1,179 @@
+use fibonacci::ecc::{coords, EccChip, EccConfig, EccPoint};
+use group::{prime::PrimeCurveAffine, Curve, Group};
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    halo2curves::pasta::{pallas, Fp},
+    plonk::{self, Circuit, Column, ConstraintSystem, Instance},
+};
+use rand_core::OsRng;
+
+#[derive(Clone)]
+struct EccCircuitConfig {
+    ecc_config: EccConfig,
+    instance: Column<Instance>,
+}
+
+// Exposes the coordinates of `[p + q (incomplete), p + q, p + p, p - p, 2p, s * p, s * G]`.
+#[derive(Default)]
+struct EccCircuit {
+    p: Value<pallas::Affine>,
+    q: Value<pallas::Affine>,
+    neg_p: Value<pallas::Affine>,
+    s: Value<pallas::Scalar>,
+}
+
+impl Circuit<Fp> for EccCircuit {
+    type Config = EccCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let ecc_config = EccChip::<pallas::Affine>::configure(meta);
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        EccCircuitConfig {
+            ecc_config,
+            instance,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), plonk::Error> {
+        let chip = EccChip::<pallas::Affine>::new(config.ecc_config);
+
+        let p = chip.witness_point(layouter.namespace(|| "p"), self.p)?;
+        let q = chip.witness_point(layouter.namespace(|| "q"), self.q)?;
+        let neg_p = chip.witness_point(layouter.namespace(|| "-p"), self.neg_p)?;
+        let bits = chip.witness_scalar_bits(layouter.namespace(|| "s"), self.s)?;
+
+        let outputs = [
+            chip.add_incomplete(layouter.namespace(|| "p + q incomplete"), &p, &q)?,
+            chip.add(layouter.namespace(|| "p + q"), &p, &q)?,
+            chip.add(layouter.namespace(|| "p + p"), &p, &p)?,
+            chip.add(layouter.namespace(|| "p - p"), &p, &neg_p)?,
+            chip.double(layouter.namespace(|| "2p"), &p)?,
+            chip.mul(layouter.namespace(|| "s * p"), &bits, &p)?,
+            chip.mul_fixed(
+                layouter.namespace(|| "s * G"),
+                &bits,
+                pallas::Affine::generator(),
+            )?,
+        ];
+
+        for (i, EccPoint { x, y }) in outputs.iter().enumerate() {
+            layouter.constrain_instance(x.cell(), config.instance, 2 * i)?;
+            layouter.constrain_instance(y.cell(), config.instance, 2 * i + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct OnCurveCircuit {
+    x: Value<Fp>,
+    y: Value<Fp>,
+}
+
+impl Circuit<Fp> for OnCurveCircuit {
+    type Config = EccConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        EccChip::<pallas::Affine>::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), plonk::Error> {
+        let chip = EccChip::<pallas::Affine>::new(config);
+
+        chip.witness_coords(layouter.namespace(|| "p"), self.x, self.y)?;
+        Ok(())
+    }
+}
+
+fn native(p: pallas::Affine, q: pallas::Affine, s: pallas::Scalar) -> Vec<Fp> {
+    let g = pallas::Affine::generator();
+    [
+        p + q,
+        p + q,
+        p + p,
+        p - p,
+        p.to_curve().double(),
+        p * s,
+        g * s,
+    ]
+    .iter()
+    .flat_map(|r| {
+        let (x, y) = coords(r.to_affine());
+        [x, y]
+    })
+    .collect()
+}
+
+fn run(circuit: &EccCircuit, public: Vec<Fp>) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let k = 12;
+    let prover = MockProver::run(k, circuit, vec![public]).unwrap();
+    prover.verify()
+}
+
+fn main() {
+    let p = pallas::Point::random(OsRng).to_affine();
+    let q = pallas::Point::random(OsRng).to_affine();
+
+    for s in [
+        pallas::Scalar::ZERO,
+        pallas::Scalar::ONE,
+        pallas::Scalar::from(0xdead_beef),
+        -pallas::Scalar::ONE,
+        pallas::Scalar::random(OsRng),
+    ] {
+        let circuit = EccCircuit {
+            p: Value::known(p),
+            q: Value::known(q),
+            neg_p: Value::known(-p),
+            s: Value::known(s),
+        };
+        let public = native(p, q, s);
+        run(&circuit, public.clone()).unwrap();
+
+        let mut wrong = public;
+        wrong[0] += Fp::ONE;
+        assert!(run(&circuit, wrong).is_err());
+    }
+
+    // a point that is not on the curve
+    let (x, y) = coords(p);
+    let circuit = OnCurveCircuit {
+        x: Value::known(x),
+        y: Value::known(y),
+    };
+    MockProver::run(4, &circuit, vec![])
+        .unwrap()
+        .assert_satisfied();
+
+    let circuit = OnCurveCircuit {
+        x: Value::known(x),
+        y: Value::known(y + Fp::ONE),
+    };
+    assert!(MockProver::run(4, &circuit, vec![])
+        .unwrap()
+        .verify()
+        .is_err());
+}
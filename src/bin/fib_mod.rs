@@ -0,0 +1,263 @@
+use ff::PrimeField;
+use fibonacci::div_mod::{DivModChip, DivModConfig};
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    halo2curves::pasta::Fp,
+    plonk::{self, Advice, Circuit, Column, ConstraintSystem, Instance, Selector},
+    poly::Rotation,
+};
+
+#[derive(Clone)]
+struct FiboModConfig<F> {
+    col: Column<Advice>,
+    sel: Selector,
+    instance: Column<Instance>,
+    div_mod_config: DivModConfig<F>,
+}
+
+impl<F: PrimeField> FiboModConfig<F> {
+    fn new(meta: &mut ConstraintSystem<F>) -> Self {
+        let col = meta.advice_column();
+        let sel = meta.selector();
+        let instance = meta.instance_column();
+
+        meta.enable_equality(col);
+        meta.enable_equality(instance);
+
+        Self {
+            col,
+            sel,
+            instance,
+            div_mod_config: DivModChip::configure(meta),
+        }
+    }
+}
+
+// The fib2 layout, with every new term reduced modulo the public `m`:
+//
+// col
+// a          <- copied from the previous step
+// b          <- copied from the previous step
+// c = a + b  -> div_mod(c, m) = (_, c mod m)
+struct FiboModChip<F> {
+    config: FiboModConfig<F>,
+    div_mod_chip: DivModChip<F>,
+}
+
+impl<F: PrimeField> FiboModChip<F> {
+    fn new(config: FiboModConfig<F>) -> Self {
+        let div_mod_chip = DivModChip::new(config.div_mod_config.clone());
+        Self {
+            config,
+            div_mod_chip,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>, config: &FiboModConfig<F>) {
+        meta.create_gate("fibonacci", |meta| {
+            let sel = meta.query_selector(config.sel);
+
+            let a = meta.query_advice(config.col, Rotation::prev());
+            let b = meta.query_advice(config.col, Rotation::cur());
+            let c = meta.query_advice(config.col, Rotation::next());
+
+            vec![sel * (a + b - c)]
+        })
+    }
+
+    fn load_modulus(
+        &self,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        layouter.assign_region(
+            || "load m",
+            |mut region| {
+                region.assign_advice_from_instance(
+                    || "m",
+                    self.config.instance,
+                    0,
+                    self.config.col,
+                    0,
+                )
+            },
+        )
+    }
+
+    fn assign_init(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Value<F>,
+        b: Value<F>,
+        m: &AssignedCell<F, F>,
+    ) -> Result<(AssignedCell<F, F>, AssignedCell<F, F>), plonk::Error> {
+        let a = self
+            .div_mod_chip
+            .load_private(layouter.namespace(|| "a"), a)?;
+        let b = self
+            .div_mod_chip
+            .load_private(layouter.namespace(|| "b"), b)?;
+
+        let (_, a) = self
+            .div_mod_chip
+            .div_mod(layouter.namespace(|| "a mod m"), &a, m)?;
+        let (_, b) = self
+            .div_mod_chip
+            .div_mod(layouter.namespace(|| "b mod m"), &b, m)?;
+
+        Ok((a, b))
+    }
+
+    fn assign(
+        &self,
+        mut layouter: impl Layouter<F>,
+        prev_b: &AssignedCell<F, F>,
+        prev_c: &AssignedCell<F, F>,
+        m: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        let c = layouter.assign_region(
+            || "assign",
+            |mut region| {
+                self.config.sel.enable(&mut region, 1)?;
+
+                let c = prev_b.value().copied() + prev_c.value();
+
+                prev_b.copy_advice(|| "copy b", &mut region, self.config.col, 0)?;
+                prev_c.copy_advice(|| "copy c", &mut region, self.config.col, 1)?;
+                region.assign_advice(|| "assign c", self.config.col, 2, || c)
+            },
+        )?;
+
+        let (_, c) = self
+            .div_mod_chip
+            .div_mod(layouter.namespace(|| "c mod m"), &c, m)?;
+        Ok(c)
+    }
+
+    fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        public: AssignedCell<F, F>,
+    ) -> Result<(), plonk::Error> {
+        layouter.constrain_instance(public.cell(), self.config.instance, 1)
+    }
+}
+
+// The public instances are `[m, F(n) mod m]`.
+#[derive(Default)]
+struct FiboModCircuit<F> {
+    a: Value<F>,
+    b: Value<F>,
+    n: usize,
+}
+
+impl<F: PrimeField> Circuit<F> for FiboModCircuit<F> {
+    type Config = FiboModConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            n: self.n,
+            ..Self::default()
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let config = FiboModConfig::new(meta);
+        FiboModChip::configure(meta, &config);
+        config
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let chip = FiboModChip::new(config);
+        chip.div_mod_chip
+            .load_table(layouter.namespace(|| "load table"))?;
+
+        let m = chip.load_modulus(layouter.namespace(|| "m"))?;
+        let (mut prev_b, mut prev_c) =
+            chip.assign_init(layouter.namespace(|| "init"), self.a, self.b, &m)?;
+
+        for i in 2..=self.n {
+            let cell_c = chip.assign(
+                layouter.namespace(|| format!("assign-{}", i)),
+                &prev_b,
+                &prev_c,
+                &m,
+            )?;
+
+            prev_b = prev_c;
+            prev_c = cell_c;
+        }
+
+        chip.expose_public(layouter.namespace(|| "expose public"), prev_c)
+    }
+}
+
+fn fibonacci_mod(a: u64, b: u64, n: usize, m: u64) -> u64 {
+    let m = m as u128;
+    let (a, b) = (a as u128 % m, b as u128 % m);
+    (1..n).fold((a, b), |(a, b), _| (b, (a + b) % m)).1 as u64
+}
+
+// The period of the Fibonacci sequence modulo `m`.
+fn pisano_period(m: u64) -> usize {
+    let mut state = (0, 1 % m);
+    for i in 1.. {
+        state = (
+            state.1,
+            ((state.0 as u128 + state.1 as u128) % m as u128) as u64,
+        );
+        if state == (0, 1 % m) {
+            return i;
+        }
+    }
+    unreachable!()
+}
+
+fn run(
+    a: u64,
+    b: u64,
+    n: usize,
+    m: u64,
+    output: u64,
+) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let k = 13;
+    let circuit = FiboModCircuit {
+        a: Value::known(Fp::from(a)),
+        b: Value::known(Fp::from(b)),
+        n,
+    };
+
+    let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(m), Fp::from(output)]]).unwrap();
+    prover.verify()
+}
+
+fn main() {
+    // `FiboCircuit` in fib2 exposes F(11) = 89.
+    assert_eq!(fibonacci_mod(0, 1, 11, u64::MAX), 89);
+    run(0, 1, 11, u64::MAX, 89).unwrap();
+    run(0, 1, 11, 10, 9).unwrap();
+    assert!(run(0, 1, 11, 10, 89).is_err());
+
+    // F(n + pi(m)) = F(n) mod m
+    let m = 10;
+    let period = pisano_period(m);
+    assert_eq!(period, 60);
+    let expected = fibonacci_mod(0, 1, 7, m);
+    run(0, 1, 7, m, expected).unwrap();
+    run(0, 1, 7 + period, m, expected).unwrap();
+
+    // Terms near 2^64, where `a + b` doesn't fit in 64 bits.
+    let m = u64::MAX - 58;
+    let n = 100;
+    let expected = fibonacci_mod(u64::MAX, u64::MAX - 1, n, m);
+    run(u64::MAX, u64::MAX - 1, n, m, expected).unwrap();
+    assert!(run(u64::MAX, u64::MAX - 1, n, m, expected ^ 1).is_err());
+
+    // Zero modulus
+    assert!(run(0, 1, 11, 0, 89).is_err());
+}
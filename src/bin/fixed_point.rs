@@ -0,0 +1,144 @@
+use ff::PrimeField;
+use fibonacci::fixed_point::{FixedPoint, FixedPointChip, FixedPointConfig};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    halo2curves::pasta::Fp,
+    plonk::{self, Circuit, Column, ConstraintSystem, Instance},
+};
+
+const SCALE: u64 = 1 << 16;
+
+type Fixed = FixedPoint<SCALE>;
+
+#[derive(Clone)]
+struct FixedPointCircuitConfig<F> {
+    fixed_point_config: FixedPointConfig<F>,
+    instance: Column<Instance>,
+}
+
+// Exposes `[x + y, x - y, x * y, x / y, x < y, relu(x)]`.
+#[derive(Default)]
+struct FixedPointCircuit {
+    x: Value<Fixed>,
+    y: Value<Fixed>,
+}
+
+impl<F: PrimeField> Circuit<F> for FixedPointCircuit {
+    type Config = FixedPointCircuitConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let fixed_point_config = FixedPointChip::<F, SCALE>::configure(meta);
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        FixedPointCircuitConfig {
+            fixed_point_config,
+            instance,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let chip = FixedPointChip::<F, SCALE>::new(config.fixed_point_config);
+
+        chip.load_table(layouter.namespace(|| "load table"))?;
+
+        let x = chip.load_private(layouter.namespace(|| "load x"), self.x)?;
+        let y = chip.load_private(layouter.namespace(|| "load y"), self.y)?;
+
+        let outputs = [
+            chip.add(layouter.namespace(|| "add"), &x, &y)?,
+            chip.sub(layouter.namespace(|| "sub"), &x, &y)?,
+            chip.mul(layouter.namespace(|| "mul"), &x, &y)?,
+            chip.div(layouter.namespace(|| "div"), &x, &y)?,
+            chip.lt(layouter.namespace(|| "lt"), &x, &y)?,
+            chip.relu(layouter.namespace(|| "relu"), &x)?,
+        ];
+
+        for (i, output) in outputs.iter().enumerate() {
+            layouter.constrain_instance(output.cell(), config.instance, i)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn native(x: Fixed, y: Fixed) -> [Fixed; 6] {
+    let lt = Fixed::from_raw((x < y) as i64);
+    [x + y, x - y, x * y, x / y, lt, x.relu()]
+}
+
+fn run(x: Fixed, y: Fixed, outputs: &[Fixed]) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let k = 10;
+    let circuit = FixedPointCircuit {
+        x: Value::known(x),
+        y: Value::known(y),
+    };
+    let public = outputs.iter().map(|v| v.to_field::<Fp>()).collect();
+
+    let prover = MockProver::run(k, &circuit, vec![public]).unwrap();
+    prover.verify()
+}
+
+fn main() {
+    let tolerance = 1.0 / SCALE as f64;
+
+    for (x, y) in [
+        (3.25, 1.5),
+        (-3.25, 1.5),
+        (0.1, 0.7),
+        (-1234.5678, 0.001),
+        (1.0, 3.0),
+        (-0.5, 2.0),
+        (0.0, 1.0),
+    ] {
+        let (fx, fy) = (Fixed::from_f64(x), Fixed::from_f64(y));
+        let outputs = native(fx, fy);
+
+        // compare against the rounded inputs so only the rounding of each op is measured
+        let (x, y) = (fx.to_f64(), fy.to_f64());
+        let expected = [
+            x + y,
+            x - y,
+            x * y,
+            x / y,
+            (x < y) as u64 as f64,
+            x.max(0.0),
+        ];
+        for (i, (output, expected)) in outputs.iter().zip(expected).enumerate() {
+            // the lt flag is encoded as a raw 0/1 rather than a scaled value
+            let output = if i == 4 {
+                output.raw() as f64
+            } else {
+                output.to_f64()
+            };
+            assert!(
+                (output - expected).abs() <= tolerance,
+                "op {} of ({}, {}): {} != {}",
+                i,
+                x,
+                y,
+                output,
+                expected
+            );
+        }
+
+        run(fx, fy, &outputs).unwrap();
+
+        // a result that is off by one unit in the last place is rejected
+        for i in 0..outputs.len() {
+            let mut wrong = outputs;
+            wrong[i] = Fixed::from_raw(wrong[i].raw() + 1);
+            assert!(run(fx, fy, &wrong).is_err());
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use fibonacci::{
+    fibo::{fib1, fib2, fib3},
+    is_zero, is_zero2, plot_layouts,
+    range_check::{example1, example2},
+};
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{floor_planner::V1, SimpleFloorPlanner, Value},
+    halo2curves::pasta::Fp,
+    plonk::{
+        self, Advice, Any, Assigned, Assignment, Challenge, Circuit, Column, ConstraintSystem,
+        Fixed, FloorPlanner, Instance, Selector,
+    },
+};
+
+// What the floor planner asks of the backend: how many regions it laid out, how many rows they
+// span and how many cells they fill.
+#[derive(Default)]
+struct Usage {
+    regions: usize,
+    rows: usize,
+    cells: usize,
+}
+
+impl Usage {
+    fn touch(&mut self, row: usize) {
+        self.rows = self.rows.max(row + 1);
+    }
+}
+
+impl<F: Field> Assignment<F> for Usage {
+    fn enter_region<NR, N>(&mut self, _: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.regions += 1;
+    }
+
+    fn annotate_column<A, AR>(&mut self, _: A, _: Column<Any>)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+    }
+
+    fn exit_region(&mut self) {}
+
+    fn enable_selector<A, AR>(&mut self, _: A, _: &Selector, row: usize) -> Result<(), plonk::Error>
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.touch(row);
+        Ok(())
+    }
+
+    fn query_instance(&self, _: Column<Instance>, _: usize) -> Result<Value<F>, plonk::Error> {
+        Ok(Value::unknown())
+    }
+
+    fn assign_advice<V, VR, A, AR>(
+        &mut self,
+        _: A,
+        _: Column<Advice>,
+        row: usize,
+        _: V,
+    ) -> Result<(), plonk::Error>
+    where
+        V: FnOnce() -> Value<VR>,
+        VR: Into<Assigned<F>>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.touch(row);
+        self.cells += 1;
+        Ok(())
+    }
+
+    fn assign_fixed<V, VR, A, AR>(
+        &mut self,
+        _: A,
+        _: Column<Fixed>,
+        row: usize,
+        _: V,
+    ) -> Result<(), plonk::Error>
+    where
+        V: FnOnce() -> Value<VR>,
+        VR: Into<Assigned<F>>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.touch(row);
+        self.cells += 1;
+        Ok(())
+    }
+
+    fn copy(
+        &mut self,
+        _: Column<Any>,
+        _: usize,
+        _: Column<Any>,
+        _: usize,
+    ) -> Result<(), plonk::Error> {
+        Ok(())
+    }
+
+    // Only pads lookup tables up to the usable rows, so it doesn't count.
+    fn fill_from_row(
+        &mut self,
+        _: Column<Fixed>,
+        _: usize,
+        _: Value<Assigned<F>>,
+    ) -> Result<(), plonk::Error> {
+        Ok(())
+    }
+
+    fn get_challenge(&self, _: Challenge) -> Value<F> {
+        Value::unknown()
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+    }
+
+    fn pop_namespace(&mut self, _: Option<String>) {}
+}
+
+fn measure<C: Circuit<Fp>>(circuit: &C) -> Usage {
+    let mut cs = ConstraintSystem::default();
+    let config = C::configure(&mut cs);
+
+    let mut usage = Usage::default();
+    C::FloorPlanner::synthesize(&mut usage, circuit, config, cs.constants().clone()).unwrap();
+    usage
+}
+
+// Returns the rows used by each planner, and renders both layouts to `floor_planner_<name>.png`.
+fn compare(name: &str, k: u32, simple: &impl Circuit<Fp>, v1: &impl Circuit<Fp>) -> (usize, usize) {
+    let usages = [("simple", measure(simple)), ("v1", measure(v1))];
+    for (planner, usage) in usages.iter() {
+        println!(
+            "{},{},{},{},{}",
+            name, planner, usage.regions, usage.rows, usage.cells
+        );
+    }
+
+    plot_layouts(
+        format!("floor_planner_{}.png", name),
+        (&format!("{} SimpleFloorPlanner", name), simple),
+        (&format!("{} V1", name), v1),
+        k,
+    );
+
+    (usages[0].1.rows, usages[1].1.rows)
+}
+
+fn main() {
+    println!("circuit,planner,regions,rows,cells");
+
+    let (a, b) = (Value::known(Fp::from(0)), Value::known(Fp::from(1)));
+    let fib1_rows = compare(
+        "fib1",
+        4,
+        &fib1::FiboCircuit::<_, SimpleFloorPlanner>::with_floor_planner(a, b),
+        &fib1::FiboCircuit::<_, V1>::with_floor_planner(a, b),
+    );
+    compare(
+        "fib2",
+        6,
+        &fib2::FiboCircuit::<_, SimpleFloorPlanner>::with_floor_planner(a, b),
+        &fib2::FiboCircuit::<_, V1>::with_floor_planner(a, b),
+    );
+    compare(
+        "fib3",
+        4,
+        &fib3::FiboCircuit::<_, SimpleFloorPlanner>::with_floor_planner(a, b),
+        &fib3::FiboCircuit::<_, V1>::with_floor_planner(a, b),
+    );
+
+    let (a, b, c) = (
+        Value::known(Fp::from(11)),
+        Value::known(Fp::from(11)),
+        Value::known(Fp::from(22)),
+    );
+    compare(
+        "is_zero",
+        4,
+        &is_zero::example::FooCircuit::<_, SimpleFloorPlanner>::with_floor_planner(a, b, c),
+        &is_zero::example::FooCircuit::<_, V1>::with_floor_planner(a, b, c),
+    );
+    compare(
+        "is_zero2",
+        4,
+        &is_zero2::example::FooCircuit::<_, SimpleFloorPlanner>::with_floor_planner(a, b, c),
+        &is_zero2::example::FooCircuit::<_, V1>::with_floor_planner(a, b, c),
+    );
+
+    let value = Value::known(Fp::from(7));
+    compare(
+        "range_check",
+        4,
+        &example1::RangeCheckCircuit::<_, 8, SimpleFloorPlanner>::with_floor_planner(value),
+        &example1::RangeCheckCircuit::<_, 8, V1>::with_floor_planner(value),
+    );
+    let value = Value::known(Fp::from(1023));
+    compare(
+        "range_check2",
+        11,
+        &example2::RangeCheckCircuit::<_, 1024, SimpleFloorPlanner>::with_floor_planner(value),
+        &example2::RangeCheckCircuit::<_, 1024, V1>::with_floor_planner(value),
+    );
+
+    let (simple, v1) = fib1_rows;
+    println!(
+        "# fib1 packs into {} rows with SimpleFloorPlanner and {} with V1",
+        simple, v1
+    );
+}
@@ -0,0 +1,140 @@
+use ff::PrimeField;
+use fibonacci::memory::{MemoryChip, MemoryConfig};
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    halo2curves::pasta::Fp,
+    plonk::{self, Advice, Circuit, Column, ConstraintSystem, Instance},
+};
+
+#[derive(Clone, Copy)]
+enum Op<F> {
+    Write(Value<F>, Value<F>),
+    Read(Value<F>),
+}
+
+#[derive(Clone)]
+struct MemoryCircuitConfig<F> {
+    advice: Column<Advice>,
+    instance: Column<Instance>,
+    memory_config: MemoryConfig<F>,
+}
+
+// Exposes the results of all reads, in order.
+struct MemoryCircuit<F> {
+    ops: Vec<Op<F>>,
+}
+
+fn load<F: Field>(
+    mut layouter: impl Layouter<F>,
+    advice: Column<Advice>,
+    value: Value<F>,
+) -> Result<AssignedCell<F, F>, plonk::Error> {
+    layouter.assign_region(
+        || "load",
+        |mut region| region.assign_advice(|| "value", advice, 0, || value),
+    )
+}
+
+impl<F: PrimeField> Circuit<F> for MemoryCircuit<F> {
+    type Config = MemoryCircuitConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        let ops = self
+            .ops
+            .iter()
+            .map(|op| match op {
+                Op::Write(..) => Op::Write(Value::unknown(), Value::unknown()),
+                Op::Read(..) => Op::Read(Value::unknown()),
+            })
+            .collect();
+
+        Self { ops }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = meta.advice_column();
+        let instance = meta.instance_column();
+        meta.enable_equality(advice);
+        meta.enable_equality(instance);
+
+        MemoryCircuitConfig {
+            advice,
+            instance,
+            memory_config: MemoryChip::configure(meta),
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let mut chip = MemoryChip::new(config.memory_config);
+        chip.load_table(layouter.namespace(|| "load table"))?;
+
+        let mut reads = 0;
+        for (i, op) in self.ops.iter().enumerate() {
+            let mut layouter = layouter.namespace(|| format!("op {}", i));
+            match *op {
+                Op::Write(addr, value) => {
+                    let addr = load(layouter.namespace(|| "addr"), config.advice, addr)?;
+                    let value = load(layouter.namespace(|| "value"), config.advice, value)?;
+                    chip.write(layouter.namespace(|| "write"), &addr, &value)?;
+                }
+                Op::Read(addr) => {
+                    let addr = load(layouter.namespace(|| "addr"), config.advice, addr)?;
+                    let value = chip.read(layouter.namespace(|| "read"), &addr)?;
+                    layouter.constrain_instance(value.cell(), config.instance, reads)?;
+                    reads += 1;
+                }
+            }
+        }
+
+        chip.finalize(layouter.namespace(|| "finalize"))
+    }
+}
+
+fn write(addr: u64, value: u64) -> Op<Fp> {
+    Op::Write(Value::known(Fp::from(addr)), Value::known(Fp::from(value)))
+}
+
+fn read(addr: u64) -> Op<Fp> {
+    Op::Read(Value::known(Fp::from(addr)))
+}
+
+fn run(ops: Vec<Op<Fp>>, reads: &[u64]) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let k = 9;
+    let circuit = MemoryCircuit { ops };
+    let public = reads.iter().map(|v| Fp::from(*v)).collect();
+
+    let prover = MockProver::run(k, &circuit, vec![public]).unwrap();
+    prover.verify()
+}
+
+fn main() {
+    let ops = vec![
+        read(7),
+        write(7, 10),
+        write(3, 20),
+        read(7),
+        write(7, 11),
+        read(3),
+        read(7),
+        read(u64::MAX),
+        write(0, 1),
+        read(0),
+    ];
+
+    run(ops.clone(), &[0, 10, 20, 11, 0, 1]).unwrap();
+
+    // stale value
+    assert!(run(ops.clone(), &[0, 10, 20, 10, 0, 1]).is_err());
+    // uninitialized memory reads as zero
+    assert!(run(ops, &[0, 10, 20, 11, 1, 1]).is_err());
+
+    run(vec![], &[]).unwrap();
+    run(vec![write(1, 1)], &[]).unwrap();
+}
@@ -0,0 +1,173 @@
+use fibonacci::permutation::{PermutationChip, PermutationConfig};
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    halo2curves::pasta::Fp,
+    plonk::{self, Advice, Circuit, Column, ConstraintSystem},
+};
+
+#[derive(Clone)]
+struct ShuffleConfig<const W: usize> {
+    values: [Column<Advice>; W],
+    permutation_config: PermutationConfig<W>,
+}
+
+struct ShuffleCircuit<F, const W: usize> {
+    a: Vec<[Value<F>; W]>,
+    b: Vec<[Value<F>; W]>,
+}
+
+impl<F: Field, const W: usize> ShuffleCircuit<F, W> {
+    fn load(
+        &self,
+        config: &ShuffleConfig<W>,
+        mut layouter: impl Layouter<F>,
+        rows: &[[Value<F>; W]],
+    ) -> Result<Vec<[AssignedCell<F, F>; W]>, plonk::Error> {
+        layouter.assign_region(
+            || "load rows",
+            |mut region| {
+                rows.iter()
+                    .enumerate()
+                    .map(|(i, row)| {
+                        let mut cells = Vec::with_capacity(W);
+                        for (j, value) in row.iter().enumerate() {
+                            cells.push(region.assign_advice(
+                                || format!("row-{}", i),
+                                config.values[j],
+                                i,
+                                || *value,
+                            )?);
+                        }
+                        Ok(cells.try_into().unwrap())
+                    })
+                    .collect()
+            },
+        )
+    }
+}
+
+impl<F: Field, const W: usize> Circuit<F> for ShuffleCircuit<F, W> {
+    type Config = ShuffleConfig<W>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            a: vec![[Value::unknown(); W]; self.a.len()],
+            b: vec![[Value::unknown(); W]; self.b.len()],
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let values = [(); W].map(|_| meta.advice_column());
+        for column in values {
+            meta.enable_equality(column);
+        }
+
+        ShuffleConfig {
+            values,
+            permutation_config: PermutationChip::configure(meta),
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let a = self.load(&config, layouter.namespace(|| "load a"), &self.a)?;
+        let b = self.load(&config, layouter.namespace(|| "load b"), &self.b)?;
+
+        let chip = PermutationChip::new(config.permutation_config);
+        chip.assert_permutation_rows(layouter.namespace(|| "permutation"), &a, &b)
+    }
+}
+
+fn run<const W: usize>(
+    a: &[[u64; W]],
+    b: &[[u64; W]],
+) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let k = 5;
+    let rows = |rows: &[[u64; W]]| {
+        rows.iter()
+            .map(|row| row.map(|v| Value::known(Fp::from(v))))
+            .collect()
+    };
+    let circuit = ShuffleCircuit::<Fp, W> {
+        a: rows(a),
+        b: rows(b),
+    };
+
+    let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+    prover.verify()
+}
+
+// Single-column shuffles go through `assert_permutation`.
+struct SliceCircuit<F> {
+    a: Vec<Value<F>>,
+    b: Vec<Value<F>>,
+}
+
+impl<F: Field> Circuit<F> for SliceCircuit<F> {
+    type Config = ShuffleConfig<1>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            a: vec![Value::unknown(); self.a.len()],
+            b: vec![Value::unknown(); self.b.len()],
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        ShuffleCircuit::<F, 1>::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let circuit = ShuffleCircuit {
+            a: self.a.iter().map(|v| [*v]).collect(),
+            b: self.b.iter().map(|v| [*v]).collect(),
+        };
+        let a = circuit.load(&config, layouter.namespace(|| "load a"), &circuit.a)?;
+        let b = circuit.load(&config, layouter.namespace(|| "load b"), &circuit.b)?;
+        let a: Vec<_> = a.into_iter().map(|[cell]| cell).collect();
+        let b: Vec<_> = b.into_iter().map(|[cell]| cell).collect();
+
+        let chip = PermutationChip::new(config.permutation_config);
+        chip.assert_permutation(layouter.namespace(|| "permutation"), &a, &b)
+    }
+}
+
+fn main() {
+    run(&[[3], [1], [4], [1], [5]], &[[1], [1], [3], [4], [5]]).unwrap();
+    run::<1>(&[], &[]).unwrap();
+
+    // Same sum and product of entries, but not the same multiset.
+    assert!(run(&[[1], [4], [6]], &[[2], [2], [6]]).is_err());
+    assert!(run(&[[1], [1], [2]], &[[1], [2], [2]]).is_err());
+
+    // Rows move as a whole.
+    run(&[[1, 10], [2, 20], [3, 30]], &[[3, 30], [1, 10], [2, 20]]).unwrap();
+    assert!(run(&[[1, 10], [2, 20]], &[[1, 20], [2, 10]]).is_err());
+
+    let values = |values: &[u64]| values.iter().map(|v| Value::known(Fp::from(*v))).collect();
+    let circuit = SliceCircuit {
+        a: values(&[7, 8, 9]),
+        b: values(&[9, 7, 8]),
+    };
+    MockProver::run(5, &circuit, vec![])
+        .unwrap()
+        .assert_satisfied();
+
+    // Lengths must match.
+    let circuit = SliceCircuit {
+        a: values(&[7, 8, 9]),
+        b: values(&[9, 7]),
+    };
+    assert!(MockProver::run(5, &circuit, vec![]).is_err());
+}
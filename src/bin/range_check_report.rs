@@ -0,0 +1,214 @@
+use std::time::{Duration, Instant};
+
+use fibonacci::{
+    prover::{keygen, prove, setup},
+    range_check::{
+        example1,
+        example2::{RangeCheckTable, LOOKUP_THRESHOLD},
+    },
+};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    halo2curves::pasta::Fp,
+    plonk::{self, Advice, Circuit, Column, ConstraintSystem, Selector},
+    poly::Rotation,
+};
+use rand_core::OsRng;
+
+// Building the expression for larger ranges recurses too deeply to be worth it.
+const MAX_EXPRESSION_RANGE: usize = 1 << 10;
+// Keygen and proving are only timed up to this extended domain size.
+const MAX_EXTENDED_K: u32 = 20;
+
+#[derive(Clone)]
+struct LookupConfig<const RANGE: usize> {
+    value: Column<Advice>,
+    q_lookup: Selector,
+    table: RangeCheckTable<Fp, RANGE>,
+}
+
+// The lookup half of `example2::RangeCheckChip`, without the expression it also configures.
+#[derive(Default)]
+struct LookupCircuit<const RANGE: usize> {
+    value: Value<Fp>,
+}
+
+impl<const RANGE: usize> Circuit<Fp> for LookupCircuit<RANGE> {
+    type Config = LookupConfig<RANGE>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let value = meta.advice_column();
+        let q_lookup = meta.complex_selector();
+        let table = RangeCheckTable::new(meta);
+
+        meta.lookup("range lookup", |meta| {
+            let q_lookup = meta.query_selector(q_lookup);
+            let value = meta.query_advice(value, Rotation::cur());
+
+            vec![(q_lookup * value, *table.table_column())]
+        });
+
+        LookupConfig {
+            value,
+            q_lookup,
+            table,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), plonk::Error> {
+        config.table.load(layouter.namespace(|| "load table"))?;
+
+        layouter.assign_region(
+            || "value",
+            |mut region| {
+                config.q_lookup.enable(&mut region, 0)?;
+                region.assign_advice(|| "value", config.value, 0, || self.value)
+            },
+        )?;
+
+        Ok(())
+    }
+}
+
+struct Report {
+    strategy: &'static str,
+    range: usize,
+    degree: usize,
+    k: u32,
+    extended_k: u32,
+    timings: Option<(Duration, Duration, usize)>,
+}
+
+// `rows` is the number of rows the circuit uses, besides the blinding rows.
+fn measure<C: Circuit<Fp>>(
+    strategy: &'static str,
+    range: usize,
+    rows: usize,
+    circuit: impl Fn() -> C,
+) -> Report {
+    let mut cs = ConstraintSystem::default();
+    C::configure(&mut cs);
+
+    let degree = cs.degree();
+    let k = (rows + cs.minimum_rows())
+        .next_power_of_two()
+        .trailing_zeros()
+        .max(4);
+
+    // As in `keygen_vk`, the quotient polynomial has degree `(degree - 1) * n`.
+    let quotient_degree = (degree - 1) as u64;
+    let mut extended_k = k;
+    while (1 << (extended_k - k)) < quotient_degree {
+        extended_k += 1;
+    }
+
+    let timings = (extended_k <= MAX_EXTENDED_K).then(|| {
+        let params = setup(k);
+
+        let start = Instant::now();
+        let pk = keygen(&params, &circuit().without_witnesses()).unwrap();
+        let keygen_time = start.elapsed();
+
+        let start = Instant::now();
+        let proof = prove(&params, &pk, circuit(), &[], OsRng).unwrap();
+        let prove_time = start.elapsed();
+
+        (keygen_time, prove_time, proof.len())
+    });
+
+    Report {
+        strategy,
+        range,
+        degree,
+        k,
+        extended_k,
+        timings,
+    }
+}
+
+fn print(report: &Report) {
+    let timings = match report.timings {
+        Some((keygen, prove, size)) => {
+            format!("{},{},{}", keygen.as_millis(), prove.as_millis(), size)
+        }
+        None => "-,-,-".to_string(),
+    };
+
+    println!(
+        "{},{},{},{},{},{}",
+        report.strategy, report.range, report.degree, report.k, report.extended_k, timings
+    );
+}
+
+fn main() {
+    println!("# IPA over Pasta (Fp circuits, Vesta commitments)");
+    println!("strategy,range,degree,k,extended_k,keygen_ms,prove_ms,proof_bytes");
+
+    let mut reports = vec![];
+
+    macro_rules! measure {
+        ($($range:expr),*) => {
+            $(
+                if $range <= MAX_EXPRESSION_RANGE {
+                    let report = measure("expression", $range, 1, || {
+                        let value = Value::known(Fp::from($range as u64 - 1));
+                        example1::RangeCheckCircuit::<Fp, $range>::new(value)
+                    });
+                    print(&report);
+                    reports.push(report);
+                }
+
+                let report = measure("lookup", $range, $range, || LookupCircuit::<$range> {
+                    value: Value::known(Fp::from($range as u64 - 1)),
+                });
+                print(&report);
+                reports.push(report);
+            )*
+        };
+    }
+
+    measure!(2, 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384, 32768, 65536);
+
+    // The largest range where the expression still proves faster than the lookup.
+    let prove_time = |strategy: &str, range: usize| {
+        reports
+            .iter()
+            .find(|report| report.strategy == strategy && report.range == range)
+            .and_then(|report| report.timings)
+            .map(|(_, prove, _)| prove)
+    };
+    let crossover = reports
+        .iter()
+        .filter(|report| report.strategy == "expression")
+        .filter(|report| {
+            match (
+                prove_time("expression", report.range),
+                prove_time("lookup", report.range),
+            ) {
+                (Some(expression), Some(lookup)) => expression <= lookup,
+                _ => false,
+            }
+        })
+        .map(|report| report.range)
+        .max();
+
+    match crossover {
+        Some(range) => println!(
+            "# recommended LOOKUP_THRESHOLD: {} (current: {})",
+            range, LOOKUP_THRESHOLD
+        ),
+        None => println!(
+            "# the lookup is always faster, recommended LOOKUP_THRESHOLD: 0 (current: {})",
+            LOOKUP_THRESHOLD
+        ),
+    }
+}
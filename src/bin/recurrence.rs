@@ -0,0 +1,132 @@
+use ff::Field;
+use fibonacci::recurrence::{
+    linear_recurrence, MultiColumnRecurrenceChip, MultiColumnRecurrenceConfig,
+    SingleColumnRecurrenceChip, SingleColumnRecurrenceConfig,
+};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    halo2curves::pasta::Fp,
+    plonk::{self, Circuit, Column, ConstraintSystem, Instance},
+};
+
+#[derive(Clone)]
+struct RecurrenceConfig<const K: usize> {
+    multi_column_config: MultiColumnRecurrenceConfig<K>,
+    single_column_config: SingleColumnRecurrenceConfig<K>,
+    instance: Column<Instance>,
+}
+
+// Computes `x_n` with both layouts and exposes it twice.
+struct RecurrenceCircuit<F, const K: usize> {
+    coeffs: [F; K],
+    init: [Value<F>; K],
+    n: usize,
+}
+
+impl<F: Field, const K: usize> Circuit<F> for RecurrenceCircuit<F, K> {
+    type Config = RecurrenceConfig<K>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            coeffs: self.coeffs,
+            init: [Value::unknown(); K],
+            n: self.n,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        RecurrenceConfig {
+            multi_column_config: MultiColumnRecurrenceChip::configure(meta),
+            single_column_config: SingleColumnRecurrenceChip::configure(meta),
+            instance,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let multi_column_chip = MultiColumnRecurrenceChip::new(config.multi_column_config);
+        let single_column_chip = SingleColumnRecurrenceChip::new(config.single_column_config);
+
+        let x = multi_column_chip.assign(
+            layouter.namespace(|| "multi column"),
+            &self.coeffs,
+            &self.init,
+            self.n,
+        )?;
+        layouter.constrain_instance(x.cell(), config.instance, 0)?;
+
+        let x = single_column_chip.assign(
+            layouter.namespace(|| "single column"),
+            &self.coeffs,
+            &self.init,
+            self.n,
+        )?;
+        layouter.constrain_instance(x.cell(), config.instance, 1)
+    }
+}
+
+fn run<const K: usize>(
+    coeffs: [u64; K],
+    init: [u64; K],
+    n: usize,
+    output: Fp,
+) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let k = 6;
+    let circuit = RecurrenceCircuit {
+        coeffs: coeffs.map(Fp::from),
+        init: init.map(|x| Value::known(Fp::from(x))),
+        n,
+    };
+
+    let prover = MockProver::run(k, &circuit, vec![vec![output, output]]).unwrap();
+    prover.verify()
+}
+
+fn check<const K: usize>(coeffs: [u64; K], init: [u64; K], n: usize, expected: Option<u64>) {
+    let xs = linear_recurrence(&coeffs.map(Fp::from), &init.map(Fp::from), n);
+    let x_n = xs[n];
+    if let Some(expected) = expected {
+        assert_eq!(x_n, Fp::from(expected));
+    }
+
+    run(coeffs, init, n, x_n).unwrap();
+    assert!(run(coeffs, init, n, x_n + Fp::ONE).is_err());
+}
+
+type Matrix = [[Fp; 2]; 2];
+
+fn mat_mul(a: &Matrix, b: &Matrix) -> Matrix {
+    std::array::from_fn(|i| std::array::from_fn(|j| a[i][0] * b[0][j] + a[i][1] * b[1][j]))
+}
+
+fn main() {
+    // Fibonacci, as exposed by `FiboCircuit` in fib1/fib2
+    check([1, 1], [0, 1], 11, Some(89));
+    // Lucas
+    check([1, 1], [2, 1], 10, Some(123));
+    // Tribonacci
+    check([1, 1, 1], [0, 0, 1], 10, Some(81));
+    // custom coefficients
+    check([2, 3], [1, 1], 20, None);
+    check([5, 0, 7, 1], [3, 1, 4, 1], 30, None);
+    // the smallest supported `n`
+    check([4, 4], [1, 2], 2, Some(12));
+
+    // The top right entry of `[[1, 1], [1, 0]]^n` is F(n).
+    let n = 40;
+    let m = [[Fp::ONE, Fp::ONE], [Fp::ONE, Fp::ZERO]];
+    let m_n = (1..n).fold(m, |acc, _| mat_mul(&acc, &m));
+    run([1, 1], [0, 1], n, m_n[0][1]).unwrap();
+
+    // a witness for other coefficients
+    let lucas = linear_recurrence(&[Fp::ONE; 2], &[Fp::from(2), Fp::ONE], 10);
+    assert!(run([1, 2], [2, 1], 10, lucas[10]).is_err());
+}
@@ -0,0 +1,64 @@
+use std::{env, fs, process};
+
+use fibonacci::input::{run, Fib1, Fib2, Fib3, IsZero, IsZero2, Mode, RangeCheck, RangeCheck2};
+
+const USAGE: &str =
+    "usage: run <circuit> <input.json> [--prove] [--seed <seed>] [--output <output.json>]
+
+circuits: fib1, fib2, fib3, is_zero, is_zero2, range_check, range_check2";
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let (Some(circuit), Some(input)) = (args.next(), args.next()) else {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    };
+
+    let mut mode = Mode::Mock;
+    let mut output = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--prove" => mode = Mode::Prove,
+            "--seed" => match args.next().and_then(|seed| seed.parse().ok()) {
+                Some(seed) => mode = Mode::ProveSeeded(seed),
+                None => {
+                    eprintln!("{}", USAGE);
+                    process::exit(2);
+                }
+            },
+            "--output" => output = args.next(),
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        }
+    }
+
+    let input = fs::read_to_string(&input).unwrap_or_else(|e| {
+        eprintln!("can't read {}: {}", input, e);
+        process::exit(1);
+    });
+
+    let result = match circuit.as_str() {
+        "fib1" => run::<Fib1>(&input, mode),
+        "fib2" => run::<Fib2>(&input, mode),
+        "fib3" => run::<Fib3>(&input, mode),
+        "is_zero" => run::<IsZero>(&input, mode),
+        "is_zero2" => run::<IsZero2>(&input, mode),
+        "range_check" => run::<RangeCheck>(&input, mode),
+        "range_check2" => run::<RangeCheck2>(&input, mode),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    match (result, output) {
+        (Ok(public), Some(output)) => fs::write(&output, public + "\n").unwrap(),
+        (Ok(public), None) => println!("{}", public),
+        (Err(e), _) => {
+            eprintln!("{:?}", e);
+            process::exit(1);
+        }
+    }
+}
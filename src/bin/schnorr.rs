@@ -0,0 +1,65 @@
+use fibonacci::schnorr::{verify, SchnorrCircuit, Signature, SigningKey};
+use group::{Curve, Group};
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::Value,
+    dev::MockProver,
+    halo2curves::pasta::{pallas, Fp},
+};
+use rand_core::OsRng;
+
+fn run(
+    pk: pallas::Affine,
+    msg: Fp,
+    sig: Signature,
+    public: Vec<Fp>,
+) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let k = 12;
+    let circuit = SchnorrCircuit {
+        pk: Value::known(pk),
+        msg: Value::known(msg),
+        sig: Value::known(sig),
+    };
+
+    let prover = MockProver::run(k, &circuit, vec![public]).unwrap();
+    prover.verify()
+}
+
+fn main() {
+    let sk = SigningKey::random(OsRng);
+    let pk = sk.public_key();
+    let msg = Fp::from(0xcafe);
+
+    let sig = sk.sign(msg, OsRng);
+    assert!(verify(pk, msg, &sig));
+    run(pk, msg, sig, SchnorrCircuit::instances(pk, msg)).unwrap();
+
+    // tampered s
+    let bad_sig = Signature {
+        s: sig.s + pallas::Scalar::ONE,
+        ..sig
+    };
+    assert!(!verify(pk, msg, &bad_sig));
+    assert!(run(pk, msg, bad_sig, SchnorrCircuit::instances(pk, msg)).is_err());
+
+    // tampered R
+    let bad_sig = Signature {
+        r: (sig.r + pallas::Point::generator()).to_affine(),
+        ..sig
+    };
+    assert!(!verify(pk, msg, &bad_sig));
+    assert!(run(pk, msg, bad_sig, SchnorrCircuit::instances(pk, msg)).is_err());
+
+    // signature over another message
+    let other_msg = msg + Fp::ONE;
+    assert!(!verify(pk, other_msg, &sig));
+    assert!(run(pk, other_msg, sig, SchnorrCircuit::instances(pk, other_msg)).is_err());
+
+    // signature under another key
+    let other_pk = SigningKey::random(OsRng).public_key();
+    assert!(!verify(other_pk, msg, &sig));
+    assert!(run(other_pk, msg, sig, SchnorrCircuit::instances(other_pk, msg)).is_err());
+
+    // a valid witness that doesn't match the public instances
+    assert!(run(pk, msg, sig, SchnorrCircuit::instances(pk, other_msg)).is_err());
+}
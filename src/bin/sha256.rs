@@ -0,0 +1,109 @@
+use ff::PrimeField;
+use fibonacci::sha256::{pad, sha256, Sha256Chip, Sha256Config, BLOCK_BYTES};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    halo2curves::pasta::Fp,
+    plonk::{self, Circuit, Column, ConstraintSystem, Instance},
+};
+
+#[derive(Clone)]
+struct Sha256CircuitConfig<F> {
+    sha256_config: Sha256Config<F>,
+    instance: Column<Instance>,
+}
+
+// Exposes the digest as 8 big-endian words.
+struct Sha256Circuit {
+    message: Vec<Value<u8>>,
+}
+
+impl<F: PrimeField> Circuit<F> for Sha256Circuit {
+    type Config = Sha256CircuitConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            message: vec![Value::unknown(); self.message.len()],
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let sha256_config = Sha256Chip::configure(meta);
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        Sha256CircuitConfig {
+            sha256_config,
+            instance,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let chip = Sha256Chip::new(config.sha256_config);
+
+        chip.load_table(layouter.namespace(|| "load table"))?;
+
+        let message = chip.load_message(layouter.namespace(|| "load message"), &self.message)?;
+        let digest = chip.digest(layouter.namespace(|| "digest"), &message)?;
+
+        for (i, word) in digest.iter().enumerate() {
+            layouter.constrain_instance(word.dense.cell(), config.instance, i)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn run(message: &[u8], digest: [u32; 8]) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let blocks = pad(message).len() / BLOCK_BYTES;
+    let k = 14 + blocks as u32;
+
+    let circuit = Sha256Circuit {
+        message: message.iter().map(|byte| Value::known(*byte)).collect(),
+    };
+    let public = digest.iter().map(|word| Fp::from(*word as u64)).collect();
+
+    let prover = MockProver::run(k, &circuit, vec![public]).unwrap();
+    prover.verify()
+}
+
+fn main() {
+    // FIPS 180-2 test vectors
+    let vectors: [(&[u8], [u32; 8]); 3] = [
+        (
+            b"",
+            [
+                0xe3b0c442, 0x98fc1c14, 0x9afbf4c8, 0x996fb924, 0x27ae41e4, 0x649b934c, 0xa495991b,
+                0x7852b855,
+            ],
+        ),
+        (
+            b"abc",
+            [
+                0xba7816bf, 0x8f01cfea, 0x414140de, 0x5dae2223, 0xb00361a3, 0x96177a9c, 0xb410ff61,
+                0xf20015ad,
+            ],
+        ),
+        (
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            [
+                0x248d6a61, 0xd20638b8, 0xe5c02693, 0x0c3e6039, 0xa33ce459, 0x64ff2167, 0xf6ecedd4,
+                0x19db06c1,
+            ],
+        ),
+    ];
+
+    for (message, digest) in vectors {
+        assert_eq!(sha256(message), digest);
+        run(message, digest).unwrap();
+
+        let mut wrong = digest;
+        wrong[7] ^= 1;
+        assert!(run(message, wrong).is_err());
+    }
+}
@@ -0,0 +1,38 @@
+use std::fs;
+
+use fibonacci::{
+    evm::{calldata, keygen, prove, render_verifier, setup, verify_in_evm},
+    fibo::{fib1::FiboCircuit, fibonacci},
+};
+use halo2_proofs::{circuit::Value, halo2curves::bn256::Fr};
+use rand_core::OsRng;
+
+// Writes the verifier of fib1's `FiboCircuit` and the calldata of a proof, then checks them in an
+// in-process EVM.
+fn main() {
+    let k = 4;
+    let params = setup(k, OsRng);
+    let pk = keygen(
+        &params,
+        &FiboCircuit::new(Value::unknown(), Value::unknown()),
+    )
+    .unwrap();
+
+    let (a, b) = (Fr::from(0), Fr::from(1));
+    let output = fibonacci(a, b, 11);
+    let circuit = FiboCircuit::new(Value::known(a), Value::known(b));
+    let proof = prove(&params, &pk, circuit, &[output], OsRng).unwrap();
+
+    let verifier = render_verifier(&params, pk.get_vk(), 1);
+    let calldata = calldata(&proof, &[output]);
+
+    fs::write("FiboVerifier.sol", &verifier).unwrap();
+    fs::write("fibo_calldata.hex", hex(&calldata)).unwrap();
+
+    let gas = verify_in_evm(&verifier, calldata).expect("the verifier rejected the proof");
+    println!("verified in {} gas", gas);
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
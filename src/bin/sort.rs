@@ -0,0 +1,59 @@
+use ff::Field;
+use fibonacci::{poseidon::poseidon_hash, sort::SortCircuit};
+use halo2_proofs::{circuit::Value, dev::MockProver, halo2curves::pasta::Fp};
+
+const N: usize = 6;
+
+fn run(
+    circuit: &SortCircuit<N>,
+    public: Vec<Fp>,
+) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let k = 10;
+    let prover = MockProver::run(k, circuit, vec![public]).unwrap();
+    prover.verify()
+}
+
+fn main() {
+    let input = [5, 3, 0, 3, u64::MAX, 42];
+
+    let circuit = SortCircuit::new(input);
+    run(&circuit, SortCircuit::instances(input)).unwrap();
+
+    // commitments to another list
+    let other = [5, 3, 0, 3, u64::MAX, 43];
+    assert!(run(&circuit, SortCircuit::instances(other)).is_err());
+
+    // a permutation of the input that isn't sorted
+    let unsorted = [0, 3, 5, 3, 42, u64::MAX];
+    let circuit = SortCircuit {
+        input: input.map(|v| Value::known(Fp::from(v))),
+        output: unsorted.map(|v| Value::known(Fp::from(v))),
+    };
+    let public = vec![
+        poseidon_hash(input.map(Fp::from)),
+        poseidon_hash(unsorted.map(Fp::from)),
+    ];
+    assert!(run(&circuit, public).is_err());
+
+    // sorted, but not a permutation of the input
+    let sorted = [0, 3, 4, 5, 42, u64::MAX];
+    let circuit = SortCircuit {
+        input: input.map(|v| Value::known(Fp::from(v))),
+        output: sorted.map(|v| Value::known(Fp::from(v))),
+    };
+    let public = vec![
+        poseidon_hash(input.map(Fp::from)),
+        poseidon_hash(sorted.map(Fp::from)),
+    ];
+    assert!(run(&circuit, public).is_err());
+
+    // `-1` sorts before `0` if differences were allowed to wrap around
+    let input = [-Fp::ONE, Fp::ZERO, Fp::ONE];
+    let circuit = SortCircuit {
+        input: input.map(Value::known),
+        output: input.map(Value::known),
+    };
+    let public = vec![poseidon_hash(input), poseidon_hash(input)];
+    let prover = MockProver::run(10, &circuit, vec![public]).unwrap();
+    assert!(prover.verify().is_err());
+}
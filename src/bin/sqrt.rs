@@ -0,0 +1,116 @@
+use ff::PrimeField;
+use fibonacci::{
+    inverse::{InverseChip, InverseConfig},
+    sqrt::{is_quadratic_residue, tonelli_shanks, SqrtChip, SqrtConfig},
+};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    halo2curves::{bn256::Fr, pasta::Fp},
+    plonk::{self, Advice, Circuit, Column, ConstraintSystem, Instance},
+};
+
+#[derive(Clone)]
+struct SqrtCircuitConfig<F> {
+    value: Column<Advice>,
+    instance: Column<Instance>,
+    sqrt_config: SqrtConfig<F>,
+    inverse_config: InverseConfig,
+}
+
+// Exposes `[s, is_qr, 1 / x]`.
+#[derive(Default)]
+struct SqrtCircuit<F> {
+    x: Value<F>,
+}
+
+impl<F: PrimeField> Circuit<F> for SqrtCircuit<F> {
+    type Config = SqrtCircuitConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let value = meta.advice_column();
+        let instance = meta.instance_column();
+        meta.enable_equality(value);
+        meta.enable_equality(instance);
+
+        SqrtCircuitConfig {
+            value,
+            instance,
+            sqrt_config: SqrtChip::configure(meta),
+            inverse_config: InverseChip::configure(meta),
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let sqrt_chip = SqrtChip::new(config.sqrt_config);
+        let inverse_chip = InverseChip::new(config.inverse_config);
+
+        let x = layouter.assign_region(
+            || "load x",
+            |mut region| region.assign_advice(|| "x", config.value, 0, || self.x),
+        )?;
+
+        let (s, is_qr) = sqrt_chip.sqrt(layouter.namespace(|| "sqrt"), &x)?;
+        let x_inv = inverse_chip.inverse(layouter.namespace(|| "inverse"), &x)?;
+
+        layouter.constrain_instance(s.cell(), config.instance, 0)?;
+        layouter.constrain_instance(is_qr.cell(), config.instance, 1)?;
+        layouter.constrain_instance(x_inv.cell(), config.instance, 2)
+    }
+}
+
+fn run<F: PrimeField>(x: F, public: Vec<F>) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let k = 4;
+    let circuit = SqrtCircuit { x: Value::known(x) };
+
+    let prover = MockProver::run(k, &circuit, vec![public]).unwrap();
+    prover.verify()
+}
+
+fn test_field<F: PrimeField>() {
+    let g = F::MULTIPLICATIVE_GENERATOR;
+
+    for x in [
+        F::ONE,
+        F::from(4),
+        F::from(1234567),
+        g,
+        g * F::from(9),
+        -F::ONE,
+    ] {
+        let is_qr = is_quadratic_residue(&x);
+        let s = tonelli_shanks(&x);
+        assert_eq!(is_qr, s.is_some());
+        assert_eq!(is_qr, bool::from(x.sqrt().is_some()));
+
+        let s = s.unwrap_or_else(|| tonelli_shanks(&(x * g)).unwrap());
+        let square = if is_qr { x } else { x * g };
+        assert_eq!(s.square(), square);
+
+        let x_inv = x.invert().unwrap();
+        run(x, vec![s, F::from(is_qr as u64), x_inv]).unwrap();
+
+        // claiming the wrong residuosity
+        assert!(run(x, vec![s, F::from(!is_qr as u64), x_inv]).is_err());
+    }
+
+    assert!(!is_quadratic_residue(&g));
+    assert!(is_quadratic_residue(&F::from(4)));
+
+    // zero has no inverse
+    assert!(run(F::ZERO, vec![F::ZERO, F::ONE, F::ZERO]).is_err());
+}
+
+fn main() {
+    test_field::<Fp>();
+    test_field::<Fr>();
+}
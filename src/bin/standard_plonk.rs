@@ -0,0 +1,75 @@
+use fibonacci::standard_plonk::{StandardPlonkChip, StandardPlonkConfig};
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    halo2curves::pasta::Fp,
+    plonk::{self, Circuit, ConstraintSystem},
+};
+
+// Proves knowledge of `x` such that `(x^3 + x + 5) / (x - 2) == out`.
+#[derive(Default)]
+struct CubicCircuit<F> {
+    x: Value<F>,
+}
+
+impl<F: Field> Circuit<F> for CubicCircuit<F> {
+    type Config = StandardPlonkConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        StandardPlonkChip::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let chip = StandardPlonkChip::new(config);
+
+        let x = chip.load_private(layouter.namespace(|| "load x"), self.x)?;
+        let two = chip.constant(layouter.namespace(|| "two"), F::from(2))?;
+        let five = chip.constant(layouter.namespace(|| "five"), F::from(5))?;
+
+        let x2 = chip.mul(layouter.namespace(|| "x^2"), &x, &x)?;
+        let x3 = chip.mul(layouter.namespace(|| "x^3"), &x2, &x)?;
+        let sum = chip.add(layouter.namespace(|| "x^3 + x"), &x3, &x)?;
+        let sum = chip.add(layouter.namespace(|| "x^3 + x + 5"), &sum, &five)?;
+
+        let divisor = chip.sub(layouter.namespace(|| "x - 2"), &x, &two)?;
+        let divisor_inv = chip.inverse(layouter.namespace(|| "1 / (x - 2)"), &divisor)?;
+        let out = chip.mul(layouter.namespace(|| "out"), &sum, &divisor_inv)?;
+
+        // recompute the numerator to exercise assert_equal
+        let numerator = chip.mul(layouter.namespace(|| "out * (x - 2)"), &out, &divisor)?;
+        chip.assert_equal(layouter.namespace(|| "check numerator"), &numerator, &sum)?;
+
+        chip.expose_public(layouter.namespace(|| "expose out"), &out, 0)
+    }
+}
+
+fn run(x: u64, out: Fp) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let k = 5;
+    let circuit = CubicCircuit {
+        x: Value::known(Fp::from(x)),
+    };
+
+    let prover = MockProver::run(k, &circuit, vec![vec![out]]).unwrap();
+    prover.verify()
+}
+
+fn main() {
+    // (27 + 3 + 5) / 1
+    run(3, Fp::from(35)).unwrap();
+    // (64 + 4 + 5) / 2
+    run(4, Fp::from(73) * Fp::from(2).invert().unwrap()).unwrap();
+
+    assert!(run(3, Fp::from(36)).is_err());
+    // x - 2 == 0 has no inverse
+    assert!(run(2, Fp::ZERO).is_err());
+}
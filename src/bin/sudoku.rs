@@ -0,0 +1,65 @@
+use fibonacci::sudoku::{is_solution, solve, Grid, SudokuCircuit};
+use halo2_proofs::{circuit::Value, dev::MockProver, halo2curves::pasta::Fp};
+
+fn run(puzzle: &Grid, solution: Grid) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let k = 9;
+    let circuit = SudokuCircuit {
+        solution: Value::known(solution),
+    };
+
+    let prover =
+        MockProver::run(k, &circuit, vec![SudokuCircuit::instances::<Fp>(puzzle)]).unwrap();
+    prover.verify()
+}
+
+fn main() {
+    let puzzle = [
+        [5, 3, 0, 0, 7, 0, 0, 0, 0],
+        [6, 0, 0, 1, 9, 5, 0, 0, 0],
+        [0, 9, 8, 0, 0, 0, 0, 6, 0],
+        [8, 0, 0, 0, 6, 0, 0, 0, 3],
+        [4, 0, 0, 8, 0, 3, 0, 0, 1],
+        [7, 0, 0, 0, 2, 0, 0, 0, 6],
+        [0, 6, 0, 0, 0, 0, 2, 8, 0],
+        [0, 0, 0, 4, 1, 9, 0, 0, 5],
+        [0, 0, 0, 0, 8, 0, 0, 7, 9],
+    ];
+
+    let solution = solve(&puzzle).unwrap();
+    assert!(is_solution(&puzzle, &solution));
+    run(&puzzle, solution).unwrap();
+
+    // another puzzle's clues
+    let mut other = puzzle;
+    other[0][2] = 1;
+    assert!(solve(&other).is_none());
+    assert!(run(&other, solution).is_err());
+
+    // a valid grid that contradicts a clue: relabeling two digits keeps every group a permutation
+    let relabeled = solution.map(|row| {
+        row.map(|digit| match digit {
+            1 => 2,
+            2 => 1,
+            digit => digit,
+        })
+    });
+    assert!(!is_solution(&puzzle, &relabeled));
+    assert!(run(&puzzle, relabeled).is_err());
+    run(&[[0; 9]; 9], relabeled).unwrap();
+
+    // swapping two empty cells in a row breaks their columns
+    assert_eq!((puzzle[0][2], puzzle[0][3]), (0, 0));
+    let mut swapped = solution;
+    swapped[0].swap(2, 3);
+    assert!(!is_solution(&puzzle, &swapped));
+    assert!(run(&puzzle, swapped).is_err());
+
+    // out of range digits
+    let mut zero = solution;
+    zero[0][2] = 0;
+    assert!(run(&puzzle, zero).is_err());
+
+    let mut ten = solution;
+    ten[0][2] = 10;
+    assert!(run(&puzzle, ten).is_err());
+}
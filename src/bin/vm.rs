@@ -0,0 +1,73 @@
+use fibonacci::{
+    fibo::{fib1, fib2, fib3, fibonacci},
+    vm::{execute, fibonacci_program, Instr, VmCircuit},
+};
+use halo2_proofs::{
+    arithmetic::Field, circuit::Value, dev::MockProver, halo2curves::pasta::Fp, plonk::Circuit,
+};
+
+const STEPS: usize = 200;
+
+fn run(circuit: &VmCircuit<Fp>, output: Fp) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let k = 9;
+    let prover = MockProver::run(k, circuit, vec![vec![output]]).unwrap();
+    prover.verify()
+}
+
+// Runs `fibonacci_program(a, b, n - 1)`, which leaves `F(n)` as `fibo` exposes it, and checks
+// both circuits against the same output.
+fn check_fibo<C: Circuit<Fp>>(k: u32, fibo: C, (a, b): (u64, u64), n: usize) {
+    let output = fibonacci(Fp::from(a), Fp::from(b), n);
+    MockProver::run(k, &fibo, vec![vec![output]])
+        .unwrap()
+        .assert_satisfied();
+
+    let circuit = VmCircuit::new(fibonacci_program(a, b, n as u64 - 1), STEPS).unwrap();
+    circuit.output().assert_if_known(|vm| *vm == output);
+
+    run(&circuit, output).unwrap();
+    assert!(run(&circuit, output + Fp::ONE).is_err());
+}
+
+fn main() {
+    // fib1/fib2 expose F(11) and fib3 F(9).
+    for (a, b) in [(0, 1), (2, 1)] {
+        let (x, y) = (Value::known(Fp::from(a)), Value::known(Fp::from(b)));
+        check_fibo(4, fib1::FiboCircuit::new(x, y), (a, b), 11);
+        check_fibo(6, fib2::FiboCircuit::new(x, y), (a, b), 11);
+        check_fibo(4, fib3::FiboCircuit::new(x, y), (a, b), 9);
+    }
+
+    let program = fibonacci_program(2, 1, 20);
+    let circuit = VmCircuit::new(program.clone(), STEPS + 50).unwrap();
+    run(&circuit, fibonacci(Fp::from(2), Fp::from(1), 21)).unwrap();
+
+    // not halted within the step limit
+    assert!(VmCircuit::<Fp>::new(program.clone(), STEPS).is_none());
+
+    // stack overflow
+    let overflow = vec![Instr::Push(1); 5];
+    assert!(execute::<Fp>(&overflow, 5).is_none());
+
+    // a trace that doesn't follow the program
+    let mut trace = execute::<Fp>(&fibonacci_program(0, 1, 10), STEPS).unwrap();
+    trace[50].stack[1] += Fp::from(1);
+    let circuit = VmCircuit {
+        program: fibonacci_program(0, 1, 10),
+        steps: STEPS,
+        trace: Value::known(trace),
+    };
+    assert!(run(&circuit, Fp::from(89)).is_err());
+
+    // the same trace checked against another program
+    let mut program = fibonacci_program(0, 1, 10);
+    program[4] = Instr::Swap(2);
+    let circuit = VmCircuit {
+        program,
+        steps: STEPS,
+        trace: VmCircuit::new(fibonacci_program(0, 1, 10), STEPS)
+            .unwrap()
+            .trace,
+    };
+    assert!(run(&circuit, Fp::from(89)).is_err());
+}
@@ -0,0 +1,183 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    plonk::{self, Circuit, ConstraintSystem},
+};
+
+use crate::standard_plonk::{StandardPlonkChip, StandardPlonkConfig};
+
+// A symbolic wire handed out by a `CircuitBuilder`, only meaningful for the builder that created it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Wire(usize);
+
+#[derive(Clone, Debug)]
+enum Op<F> {
+    Private(Value<F>),
+    Constant(F),
+    Add(Wire, Wire),
+    Sub(Wire, Wire),
+    Mul(Wire, Wire),
+    Inverse(Wire),
+}
+
+// Records arithmetic on wires, e.g.
+//
+//  let mut b = CircuitBuilder::new();
+//  let x = b.private(Value::known(x));
+//  let y = b.mul(x, x);
+//  b.expose(y);
+//  let circuit = b.build();
+//
+// The recorded ops are laid out on a `StandardPlonkChip`, one region each.
+#[derive(Clone, Debug, Default)]
+pub struct CircuitBuilder<F> {
+    ops: Vec<Op<F>>,
+    values: Vec<Value<F>>,
+    equalities: Vec<(Wire, Wire)>,
+    public: Vec<Wire>,
+}
+
+impl<F: Field> CircuitBuilder<F> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn private(&mut self, value: Value<F>) -> Wire {
+        self.push(Op::Private(value), value)
+    }
+
+    pub fn constant(&mut self, value: F) -> Wire {
+        self.push(Op::Constant(value), Value::known(value))
+    }
+
+    pub fn add(&mut self, a: Wire, b: Wire) -> Wire {
+        let value = self.value(a) + self.value(b);
+        self.push(Op::Add(a, b), value)
+    }
+
+    pub fn sub(&mut self, a: Wire, b: Wire) -> Wire {
+        let value = self.value(a) - self.value(b);
+        self.push(Op::Sub(a, b), value)
+    }
+
+    pub fn mul(&mut self, a: Wire, b: Wire) -> Wire {
+        let value = self.value(a) * self.value(b);
+        self.push(Op::Mul(a, b), value)
+    }
+
+    pub fn inverse(&mut self, a: Wire) -> Wire {
+        let value = self.value(a).map(|a| a.invert().unwrap_or(F::ZERO));
+        self.push(Op::Inverse(a), value)
+    }
+
+    pub fn assert_equal(&mut self, a: Wire, b: Wire) {
+        self.equalities.push((a, b));
+    }
+
+    // Public inputs are laid out in the order they are exposed.
+    pub fn expose(&mut self, wire: Wire) {
+        self.public.push(wire);
+    }
+
+    pub fn value(&self, wire: Wire) -> Value<F> {
+        self.values[wire.0]
+    }
+
+    pub fn build(self) -> BuiltCircuit<F> {
+        BuiltCircuit { builder: self }
+    }
+
+    fn push(&mut self, op: Op<F>, value: Value<F>) -> Wire {
+        self.ops.push(op);
+        self.values.push(value);
+        Wire(self.ops.len() - 1)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct BuiltCircuit<F> {
+    builder: CircuitBuilder<F>,
+}
+
+impl<F: Field> BuiltCircuit<F> {
+    // The values of the exposed wires, unknown if any of them is.
+    pub fn public_inputs(&self) -> Value<Vec<F>> {
+        self.builder
+            .public
+            .iter()
+            .map(|wire| self.builder.value(*wire))
+            .collect()
+    }
+
+    // The smallest `k` that fits every op together with the blinding rows.
+    pub fn min_k(&self) -> u32 {
+        let mut meta = ConstraintSystem::default();
+        Self::configure(&mut meta);
+
+        let rows = self.builder.ops.len() + meta.minimum_rows();
+        rows.next_power_of_two().trailing_zeros()
+    }
+}
+
+impl<F: Field> Circuit<F> for BuiltCircuit<F> {
+    type Config = StandardPlonkConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        let mut builder = self.builder.clone();
+        for op in builder.ops.iter_mut() {
+            if let Op::Private(value) = op {
+                *value = Value::unknown();
+            }
+        }
+        for value in builder.values.iter_mut() {
+            *value = Value::unknown();
+        }
+
+        Self { builder }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        StandardPlonkChip::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let chip = StandardPlonkChip::new(config);
+
+        let mut cells: Vec<AssignedCell<F, F>> = Vec::with_capacity(self.builder.ops.len());
+        for (i, op) in self.builder.ops.iter().enumerate() {
+            let layouter = layouter.namespace(|| format!("op-{}", i));
+            let cell = match op {
+                Op::Private(value) => chip.load_private(layouter, *value)?,
+                Op::Constant(value) => chip.constant(layouter, *value)?,
+                Op::Add(a, b) => chip.add(layouter, &cells[a.0], &cells[b.0])?,
+                Op::Sub(a, b) => chip.sub(layouter, &cells[a.0], &cells[b.0])?,
+                Op::Mul(a, b) => chip.mul(layouter, &cells[a.0], &cells[b.0])?,
+                Op::Inverse(a) => chip.inverse(layouter, &cells[a.0])?,
+            };
+            cells.push(cell);
+        }
+
+        for (i, (a, b)) in self.builder.equalities.iter().enumerate() {
+            chip.assert_equal(
+                layouter.namespace(|| format!("assert equal-{}", i)),
+                &cells[a.0],
+                &cells[b.0],
+            )?;
+        }
+
+        for (row, wire) in self.builder.public.iter().enumerate() {
+            chip.expose_public(
+                layouter.namespace(|| format!("expose-{}", row)),
+                &cells[wire.0],
+                row,
+            )?;
+        }
+
+        Ok(())
+    }
+}
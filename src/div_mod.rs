@@ -0,0 +1,151 @@
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, Value},
+    plonk::{self, Advice, Column, ConstraintSystem, Constraints, Expression, Selector},
+    poly::Rotation,
+};
+
+use crate::{
+    fe_to_u128,
+    range_check::{
+        decompose::{DecomposeChip, DecomposeConfig},
+        example2::RangeCheckTable,
+    },
+};
+
+// u64 values fit in 8 byte limbs.
+const NUM_LIMBS: usize = 8;
+
+#[derive(Clone)]
+pub struct DivModConfig<F> {
+    a: Column<Advice>,
+    d: Column<Advice>,
+    q: Column<Advice>,
+    r: Column<Advice>,
+    // d - r - 1, which is range checked to enforce `r < d`
+    diff: Column<Advice>,
+    sel: Selector,
+
+    decompose_config: DecomposeConfig<F>,
+}
+
+pub struct DivModChip<F> {
+    config: DivModConfig<F>,
+}
+
+impl<F: PrimeField> DivModChip<F> {
+    pub fn new(config: DivModConfig<F>) -> Self {
+        Self { config }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> DivModConfig<F> {
+        let a = meta.advice_column();
+        let d = meta.advice_column();
+        let q = meta.advice_column();
+        let r = meta.advice_column();
+        let diff = meta.advice_column();
+        let sel = meta.selector();
+
+        for col in [a, d, q, r, diff] {
+            meta.enable_equality(col);
+        }
+
+        let table = RangeCheckTable::new(meta);
+        let decompose_config = DecomposeChip::configure(meta, table);
+
+        meta.create_gate("div mod", |meta| {
+            let sel = meta.query_selector(sel);
+
+            let a = meta.query_advice(a, Rotation::cur());
+            let d = meta.query_advice(d, Rotation::cur());
+            let q = meta.query_advice(q, Rotation::cur());
+            let r = meta.query_advice(r, Rotation::cur());
+            let diff = meta.query_advice(diff, Rotation::cur());
+
+            Constraints::with_selector(
+                sel,
+                [
+                    ("a = q * d + r", a - (q * d.clone() + r.clone())),
+                    (
+                        "diff = d - r - 1",
+                        diff - (d - r - Expression::Constant(F::ONE)),
+                    ),
+                ],
+            )
+        });
+
+        DivModConfig {
+            a,
+            d,
+            q,
+            r,
+            diff,
+            sel,
+            decompose_config,
+        }
+    }
+
+    pub fn load_table(&self, layouter: impl Layouter<F>) -> Result<(), plonk::Error> {
+        DecomposeChip::new(self.config.decompose_config.clone()).load_table(layouter)
+    }
+
+    pub fn load_private(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: Value<F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        layouter.assign_region(
+            || "load private",
+            |mut region| region.assign_advice(|| "private", self.config.a, 0, || value),
+        )
+    }
+
+    // Returns `(a / d, a % d)`. A zero divisor makes `r < d` unsatisfiable. `a` may exceed 64 bits
+    // as long as the quotient doesn't.
+    pub fn div_mod(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        d: &AssignedCell<F, F>,
+    ) -> Result<(AssignedCell<F, F>, AssignedCell<F, F>), plonk::Error> {
+        let (q, r) = a
+            .value()
+            .zip(d.value())
+            .map(|(a, d)| {
+                let (a, d) = (fe_to_u128(a), fe_to_u128(d));
+                match d {
+                    0 => (F::ZERO, F::from_u128(a)),
+                    _ => (F::from_u128(a / d), F::from_u128(a % d)),
+                }
+            })
+            .unzip();
+
+        let (d, q, r, diff) = layouter.assign_region(
+            || "div mod",
+            |mut region| {
+                self.config.sel.enable(&mut region, 0)?;
+
+                a.copy_advice(|| "a", &mut region, self.config.a, 0)?;
+                let d = d.copy_advice(|| "d", &mut region, self.config.d, 0)?;
+                let q = region.assign_advice(|| "q", self.config.q, 0, || q)?;
+                let r = region.assign_advice(|| "r", self.config.r, 0, || r)?;
+
+                let diff = d.value().copied() - r.value() - Value::known(F::ONE);
+                let diff = region.assign_advice(|| "diff", self.config.diff, 0, || diff)?;
+
+                Ok((d, q, r, diff))
+            },
+        )?;
+
+        let decompose_chip = DecomposeChip::new(self.config.decompose_config.clone());
+        for (name, cell) in [("d", &d), ("q", &q), ("r", &r), ("diff", &diff)] {
+            decompose_chip.range_check(
+                layouter.namespace(|| format!("range check {}", name)),
+                cell,
+                NUM_LIMBS,
+            )?;
+        }
+
+        Ok((q, r))
+    }
+}
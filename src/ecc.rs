@@ -0,0 +1,732 @@
+use std::marker::PhantomData;
+
+use ff::{Field, PrimeField};
+use halo2_proofs::{
+    arithmetic::CurveAffine,
+    circuit::{AssignedCell, Layouter, Region, Value},
+    plonk::{self, Advice, Column, ConstraintSystem, Constraints, Expression, Fixed, Selector},
+    poly::Rotation,
+};
+
+// The identity is represented by `(0, 0)`, which is never on a curve with `b != 0`.
+#[derive(Clone, Debug)]
+pub struct EccPoint<F: Field> {
+    pub x: AssignedCell<F, F>,
+    pub y: AssignedCell<F, F>,
+}
+
+impl<F: Field> EccPoint<F> {
+    pub fn value<C: CurveAffine<Base = F>>(&self) -> Value<C> {
+        self.x
+            .value()
+            .zip(self.y.value())
+            .map(|(x, y)| from_coords(*x, *y))
+    }
+}
+
+pub fn coords<C: CurveAffine>(p: C) -> (C::Base, C::Base) {
+    Option::from(p.coordinates().map(|c| (*c.x(), *c.y())))
+        .unwrap_or((C::Base::ZERO, C::Base::ZERO))
+}
+
+pub fn from_coords<C: CurveAffine>(x: C::Base, y: C::Base) -> C {
+    if x.is_zero_vartime() && y.is_zero_vartime() {
+        C::identity()
+    } else {
+        C::from_xy(x, y).unwrap()
+    }
+}
+
+fn inv0<F: Field>(v: F) -> F {
+    v.invert().unwrap_or(F::ZERO)
+}
+
+// Little-endian bits of a scalar.
+pub fn scalar_bits<F: PrimeField>(v: &F) -> Vec<bool> {
+    let repr = v.to_repr();
+    (0..F::NUM_BITS as usize)
+        .map(|i| (repr.as_ref()[i / 8] >> (i % 8)) & 1 == 1)
+        .collect()
+}
+
+#[derive(Clone, Debug)]
+pub struct EccConfig {
+    x_p: Column<Advice>,
+    y_p: Column<Advice>,
+    x_q: Column<Advice>,
+    y_q: Column<Advice>,
+    x_r: Column<Advice>,
+    y_r: Column<Advice>,
+    lambda: Column<Advice>,
+    alpha: Column<Advice>,
+    beta: Column<Advice>,
+    gamma: Column<Advice>,
+    delta: Column<Advice>,
+    bit: Column<Advice>,
+    z: Column<Advice>,
+
+    fixed_x: Column<Fixed>,
+    fixed_y: Column<Fixed>,
+
+    q_on_curve: Selector,
+    q_add_incomplete: Selector,
+    q_add: Selector,
+    q_double: Selector,
+    q_mask: Selector,
+    q_mask_fixed: Selector,
+    q_bool: Selector,
+    q_decompose: Selector,
+    q_zero: Selector,
+    q_canonical: Selector,
+}
+
+// Affine arithmetic for a short Weierstrass curve `y^2 = x^3 + a*x + b` whose base field is the
+// circuit field, e.g. Pallas over Pasta `Fp`.
+pub struct EccChip<C: CurveAffine> {
+    config: EccConfig,
+    _marker: PhantomData<C>,
+}
+
+impl<C: CurveAffine> EccChip<C> {
+    pub fn new(config: EccConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<C::Base>) -> EccConfig {
+        let [x_p, y_p, x_q, y_q, x_r, y_r, lambda, alpha, beta, gamma, delta, bit, z] =
+            [(); 13].map(|_| meta.advice_column());
+        for col in [x_p, y_p, x_q, y_q, x_r, y_r, bit, z] {
+            meta.enable_equality(col);
+        }
+
+        let fixed_x = meta.fixed_column();
+        let fixed_y = meta.fixed_column();
+
+        let [q_on_curve, q_add_incomplete, q_add, q_double, q_mask, q_mask_fixed, q_bool, q_decompose, q_zero, q_canonical] =
+            [(); 10].map(|_| meta.selector());
+
+        let constant = |v: C::Base| Expression::Constant(v);
+        let one = || constant(C::Base::ONE);
+        let two = || constant(C::Base::from(2));
+        let three = || constant(C::Base::from(3));
+
+        meta.create_gate("on curve", |meta| {
+            let sel = meta.query_selector(q_on_curve);
+            let x = meta.query_advice(x_p, Rotation::cur());
+            let y = meta.query_advice(y_p, Rotation::cur());
+
+            let rhs = x.clone() * x.clone() * x.clone() + constant(C::a()) * x + constant(C::b());
+            Constraints::with_selector(sel, [y.clone() * y - rhs])
+        });
+
+        meta.create_gate("incomplete add", |meta| {
+            let sel = meta.query_selector(q_add_incomplete);
+            let [x_p, y_p, x_q, y_q, x_r, y_r, lambda] = [x_p, y_p, x_q, y_q, x_r, y_r, lambda]
+                .map(|col| meta.query_advice(col, Rotation::cur()));
+
+            Constraints::with_selector(
+                sel,
+                [
+                    (x_q.clone() - x_p.clone()) * lambda.clone() - (y_q - y_p.clone()),
+                    lambda.clone() * lambda.clone() - x_p.clone() - x_q - x_r.clone(),
+                    lambda * (x_p - x_r) - y_p - y_r,
+                ],
+            )
+        });
+
+        meta.create_gate("double", |meta| {
+            let sel = meta.query_selector(q_double);
+            let [x_p, y_p, x_r, y_r, lambda] =
+                [x_p, y_p, x_r, y_r, lambda].map(|col| meta.query_advice(col, Rotation::cur()));
+
+            Constraints::with_selector(
+                sel,
+                [
+                    two() * y_p.clone() * lambda.clone()
+                        - three() * x_p.clone() * x_p.clone()
+                        - constant(C::a()),
+                    lambda.clone() * lambda.clone() - two() * x_p.clone() - x_r.clone(),
+                    lambda * (x_p - x_r) - y_p - y_r,
+                ],
+            )
+        });
+
+        // https://zcash.github.io/halo2/design/gadgets/ecc/addition.html#complete-addition
+        meta.create_gate("complete add", |meta| {
+            let sel = meta.query_selector(q_add);
+            let [x_p, y_p, x_q, y_q, x_r, y_r, lambda, alpha, beta, gamma, delta] = [
+                x_p, y_p, x_q, y_q, x_r, y_r, lambda, alpha, beta, gamma, delta,
+            ]
+            .map(|col| meta.query_advice(col, Rotation::cur()));
+
+            let dx = x_q.clone() - x_p.clone();
+            let sy = y_q.clone() + y_p.clone();
+            let if_alpha = dx.clone() * alpha;
+            let if_beta = x_p.clone() * beta;
+            let if_gamma = x_q.clone() * gamma;
+            let if_delta = sy.clone() * delta;
+
+            let x_r_eq = lambda.clone() * lambda.clone() - x_p.clone() - x_q.clone() - x_r.clone();
+            let y_r_eq = lambda.clone() * (x_p.clone() - x_r.clone()) - y_p.clone() - y_r.clone();
+            let both_non_identity = x_p.clone() * x_q.clone();
+
+            Constraints::with_selector(
+                sel,
+                [
+                    dx.clone() * (dx.clone() * lambda.clone() - (y_q.clone() - y_p.clone())),
+                    (one() - if_alpha.clone())
+                        * (two() * y_p.clone() * lambda
+                            - three() * x_p.clone() * x_p.clone()
+                            - constant(C::a())),
+                    both_non_identity.clone() * dx.clone() * x_r_eq.clone(),
+                    both_non_identity.clone() * dx * y_r_eq.clone(),
+                    both_non_identity.clone() * sy.clone() * x_r_eq,
+                    both_non_identity * sy * y_r_eq,
+                    (one() - if_beta.clone()) * (x_r.clone() - x_q.clone()),
+                    (one() - if_beta) * (y_r.clone() - y_q),
+                    (one() - if_gamma.clone()) * (x_r.clone() - x_p),
+                    (one() - if_gamma) * (y_r.clone() - y_p),
+                    (one() - if_alpha.clone() - if_delta.clone()) * x_r,
+                    (one() - if_alpha - if_delta) * y_r,
+                ],
+            )
+        });
+
+        meta.create_gate("mask", |meta| {
+            let sel = meta.query_selector(q_mask);
+            let [x_p, y_p, x_r, y_r, bit] =
+                [x_p, y_p, x_r, y_r, bit].map(|col| meta.query_advice(col, Rotation::cur()));
+
+            Constraints::with_selector(sel, [x_r - bit.clone() * x_p, y_r - bit * y_p])
+        });
+
+        meta.create_gate("mask fixed", |meta| {
+            let sel = meta.query_selector(q_mask_fixed);
+            let [x_r, y_r, bit] =
+                [x_r, y_r, bit].map(|col| meta.query_advice(col, Rotation::cur()));
+            let fixed_x = meta.query_fixed(fixed_x, Rotation::cur());
+            let fixed_y = meta.query_fixed(fixed_y, Rotation::cur());
+
+            Constraints::with_selector(sel, [x_r - bit.clone() * fixed_x, y_r - bit * fixed_y])
+        });
+
+        meta.create_gate("bool", |meta| {
+            let sel = meta.query_selector(q_bool);
+            let bit = meta.query_advice(bit, Rotation::cur());
+
+            Constraints::with_selector(sel, [bit.clone() * (one() - bit)])
+        });
+
+        // z_i = 2 * z_{i+1} + bit_i
+        meta.create_gate("decompose", |meta| {
+            let sel = meta.query_selector(q_decompose);
+            let bit = meta.query_advice(bit, Rotation::cur());
+            let z_cur = meta.query_advice(z, Rotation::cur());
+            let z_next = meta.query_advice(z, Rotation::next());
+
+            Constraints::with_selector(
+                sel,
+                [
+                    bit.clone() * (one() - bit.clone()),
+                    z_cur - two() * z_next - bit,
+                ],
+            )
+        });
+
+        meta.create_gate("zero", |meta| {
+            let sel = meta.query_selector(q_zero);
+            let z = meta.query_advice(z, Rotation::cur());
+
+            Constraints::with_selector(sel, [z])
+        });
+
+        // From the most significant bit down, `z` flags whether the bits so far equal those of
+        // `p - 1` (in `fixed_x`). While they do, a bit can only be set where `p - 1` has one.
+        // `fixed_y` marks the first row, where the flag starts at 1.
+        meta.create_gate("canonical", |meta| {
+            let sel = meta.query_selector(q_canonical);
+            let bit = meta.query_advice(bit, Rotation::cur());
+            let eq_cur = meta.query_advice(z, Rotation::cur());
+            let eq_next = meta.query_advice(z, Rotation::next());
+            let bound = meta.query_fixed(fixed_x, Rotation::cur());
+            let first = meta.query_fixed(fixed_y, Rotation::cur());
+
+            Constraints::with_selector(
+                sel,
+                [
+                    first * (eq_cur.clone() - one()),
+                    (one() - bound.clone()) * eq_cur.clone() * bit.clone(),
+                    eq_next - eq_cur * (bound.clone() * bit + one() - bound),
+                ],
+            )
+        });
+
+        EccConfig {
+            x_p,
+            y_p,
+            x_q,
+            y_q,
+            x_r,
+            y_r,
+            lambda,
+            alpha,
+            beta,
+            gamma,
+            delta,
+            bit,
+            z,
+            fixed_x,
+            fixed_y,
+            q_on_curve,
+            q_add_incomplete,
+            q_add,
+            q_double,
+            q_mask,
+            q_mask_fixed,
+            q_bool,
+            q_decompose,
+            q_zero,
+            q_canonical,
+        }
+    }
+
+    // Witnesses a non-identity point and checks it is on the curve.
+    pub fn witness_point(
+        &self,
+        layouter: impl Layouter<C::Base>,
+        point: Value<C>,
+    ) -> Result<EccPoint<C::Base>, plonk::Error> {
+        let (x, y) = point.map(coords).unzip();
+        self.witness_coords(layouter, x, y)
+    }
+
+    pub fn witness_coords(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        x: Value<C::Base>,
+        y: Value<C::Base>,
+    ) -> Result<EccPoint<C::Base>, plonk::Error> {
+        layouter.assign_region(
+            || "witness point",
+            |mut region| {
+                self.config.q_on_curve.enable(&mut region, 0)?;
+
+                let x = region.assign_advice(|| "x", self.config.x_p, 0, || x)?;
+                let y = region.assign_advice(|| "y", self.config.y_p, 0, || y)?;
+
+                Ok(EccPoint { x, y })
+            },
+        )
+    }
+
+    pub fn assert_on_curve(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        p: &EccPoint<C::Base>,
+    ) -> Result<(), plonk::Error> {
+        layouter.assign_region(
+            || "assert on curve",
+            |mut region| {
+                self.config.q_on_curve.enable(&mut region, 0)?;
+                self.copy_p(&mut region, p)?;
+                Ok(())
+            },
+        )
+    }
+
+    pub fn constrain_equal(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        p: &EccPoint<C::Base>,
+        q: &EccPoint<C::Base>,
+    ) -> Result<(), plonk::Error> {
+        layouter.assign_region(
+            || "constrain equal",
+            |mut region| {
+                region.constrain_equal(p.x.cell(), q.x.cell())?;
+                region.constrain_equal(p.y.cell(), q.y.cell())
+            },
+        )
+    }
+
+    // Requires `p != ±q` and neither of them to be the identity.
+    pub fn add_incomplete(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        p: &EccPoint<C::Base>,
+        q: &EccPoint<C::Base>,
+    ) -> Result<EccPoint<C::Base>, plonk::Error> {
+        layouter.assign_region(
+            || "incomplete add",
+            |mut region| {
+                self.config.q_add_incomplete.enable(&mut region, 0)?;
+
+                let (x_p, y_p) = self.copy_p(&mut region, p)?;
+                let (x_q, y_q) = self.copy_q(&mut region, q)?;
+
+                let lambda = (y_q - y_p) * (x_q - x_p).map(inv0);
+                self.assign_lambda_and_r(&mut region, lambda, x_p, y_p, x_q)
+            },
+        )
+    }
+
+    // Requires `p` not to be the identity, which also rules out 2-torsion on prime order curves.
+    pub fn double(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        p: &EccPoint<C::Base>,
+    ) -> Result<EccPoint<C::Base>, plonk::Error> {
+        layouter.assign_region(
+            || "double",
+            |mut region| {
+                self.config.q_double.enable(&mut region, 0)?;
+
+                let (x_p, y_p) = self.copy_p(&mut region, p)?;
+
+                let lambda = (x_p * x_p * Value::known(C::Base::from(3)) + Value::known(C::a()))
+                    * (y_p * Value::known(C::Base::from(2))).map(inv0);
+                self.assign_lambda_and_r(&mut region, lambda, x_p, y_p, x_p)
+            },
+        )
+    }
+
+    pub fn add(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        p: &EccPoint<C::Base>,
+        q: &EccPoint<C::Base>,
+    ) -> Result<EccPoint<C::Base>, plonk::Error> {
+        layouter.assign_region(
+            || "complete add",
+            |mut region| {
+                self.config.q_add.enable(&mut region, 0)?;
+
+                let (x_p, y_p) = self.copy_p(&mut region, p)?;
+                let (x_q, y_q) = self.copy_q(&mut region, q)?;
+
+                let lambda = x_p
+                    .zip(y_p)
+                    .zip(x_q.zip(y_q))
+                    .map(|((x_p, y_p), (x_q, y_q))| {
+                        if x_q != x_p {
+                            (y_q - y_p) * inv0(x_q - x_p)
+                        } else if !y_p.is_zero_vartime() {
+                            (x_p.square() * C::Base::from(3) + C::a()) * inv0(y_p.double())
+                        } else {
+                            C::Base::ZERO
+                        }
+                    });
+                let alpha = (x_q - x_p).map(inv0);
+                let beta = x_p.map(inv0);
+                let gamma = x_q.map(inv0);
+                let delta = x_p.zip(x_q).zip(y_p + y_q).map(|((x_p, x_q), sy)| {
+                    if x_q == x_p {
+                        inv0(sy)
+                    } else {
+                        C::Base::ZERO
+                    }
+                });
+
+                let p = x_p.zip(y_p).map(|(x, y)| from_coords::<C>(x, y));
+                let q = x_q.zip(y_q).map(|(x, y)| from_coords::<C>(x, y));
+                let (x_r, y_r) = p.zip(q).map(|(p, q)| coords::<C>((p + q).into())).unzip();
+
+                let mut assign =
+                    |name: &'static str, col: Column<Advice>, value: Value<C::Base>| {
+                        region.assign_advice(|| name, col, 0, || value)
+                    };
+                assign("lambda", self.config.lambda, lambda)?;
+                assign("alpha", self.config.alpha, alpha)?;
+                assign("beta", self.config.beta, beta)?;
+                assign("gamma", self.config.gamma, gamma)?;
+                assign("delta", self.config.delta, delta)?;
+                let x = assign("x_r", self.config.x_r, x_r)?;
+                let y = assign("y_r", self.config.y_r, y_r)?;
+
+                Ok(EccPoint { x, y })
+            },
+        )
+    }
+
+    // Witnesses the little-endian bits of a scalar, which may not fit in the base field.
+    pub fn witness_scalar_bits(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        scalar: Value<C::Scalar>,
+    ) -> Result<Vec<AssignedCell<C::Base, C::Base>>, plonk::Error> {
+        let bits = scalar.map(|s| scalar_bits(&s));
+
+        layouter.assign_region(
+            || "witness scalar bits",
+            |mut region| {
+                (0..C::Scalar::NUM_BITS as usize)
+                    .map(|i| {
+                        self.config.q_bool.enable(&mut region, i)?;
+                        let bit = bits.as_ref().map(|bits| C::Base::from(bits[i] as u64));
+                        region.assign_advice(|| format!("bit-{}", i), self.config.bit, i, || bit)
+                    })
+                    .collect()
+            },
+        )
+    }
+
+    // Decomposes a base field element into `num_bits` little-endian bits, with `value` as the
+    // running sum `z_0` and `z_{num_bits} = 0`. With `C::Base::NUM_BITS` bits, both `v` and
+    // `v + p` fit, so `assert_canonical` is needed for the bits to be unique.
+    pub fn decompose(
+        &self,
+        layouter: impl Layouter<C::Base>,
+        value: &AssignedCell<C::Base, C::Base>,
+        num_bits: usize,
+    ) -> Result<Vec<AssignedCell<C::Base, C::Base>>, plonk::Error> {
+        let bits = value.value().map(|v| scalar_bits(v)[..num_bits].to_vec());
+        self.decompose_bits(layouter, value, bits, num_bits)
+    }
+
+    // `decompose` with the bits witnessed by the caller, which the constraints don't check are
+    // those of the canonical `value`.
+    pub fn decompose_bits(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        value: &AssignedCell<C::Base, C::Base>,
+        bits: Value<Vec<bool>>,
+        num_bits: usize,
+    ) -> Result<Vec<AssignedCell<C::Base, C::Base>>, plonk::Error> {
+        let two_inv = C::Base::TWO_INV;
+
+        layouter.assign_region(
+            || "decompose",
+            |mut region| {
+                let mut z = value.copy_advice(|| "z_0", &mut region, self.config.z, 0)?;
+                let mut cells = Vec::with_capacity(num_bits);
+
+                for i in 0..num_bits {
+                    self.config.q_decompose.enable(&mut region, i)?;
+
+                    let bit = bits.as_ref().map(|bits| C::Base::from(bits[i] as u64));
+                    let z_next = (z.value().copied() - bit) * Value::known(two_inv);
+
+                    cells.push(region.assign_advice(
+                        || format!("bit-{}", i),
+                        self.config.bit,
+                        i,
+                        || bit,
+                    )?);
+                    z = region.assign_advice(
+                        || format!("z_{}", i + 1),
+                        self.config.z,
+                        i + 1,
+                        || z_next,
+                    )?;
+                }
+
+                self.config.q_zero.enable(&mut region, num_bits)?;
+
+                Ok(cells)
+            },
+        )
+    }
+
+    // Constrains the `C::Base::NUM_BITS` little-endian `bits` to encode a value below the modulus,
+    // by comparing them with the bits of `p - 1`.
+    pub fn assert_canonical(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        bits: &[AssignedCell<C::Base, C::Base>],
+    ) -> Result<(), plonk::Error> {
+        let bound = scalar_bits(&-C::Base::ONE);
+        assert_eq!(bits.len(), bound.len());
+
+        layouter.assign_region(
+            || "assert canonical",
+            |mut region| {
+                let mut eq = region.assign_advice(
+                    || "eq",
+                    self.config.z,
+                    0,
+                    || Value::known(C::Base::ONE),
+                )?;
+
+                for (row, (bit, bound)) in bits.iter().zip(bound.iter()).rev().enumerate() {
+                    self.config.q_canonical.enable(&mut region, row)?;
+
+                    let bit = bit.copy_advice(|| "bit", &mut region, self.config.bit, row)?;
+                    region.assign_fixed(
+                        || "bound",
+                        self.config.fixed_x,
+                        row,
+                        || Value::known(C::Base::from(*bound as u64)),
+                    )?;
+                    region.assign_fixed(
+                        || "first",
+                        self.config.fixed_y,
+                        row,
+                        || Value::known(C::Base::from((row == 0) as u64)),
+                    )?;
+
+                    let eq_next =
+                        eq.value().zip(bit.value()).map(
+                            |(eq, bit)| {
+                                if *bound {
+                                    *eq * bit
+                                } else {
+                                    *eq
+                                }
+                            },
+                        );
+                    eq = region.assign_advice(|| "eq", self.config.z, row + 1, || eq_next)?;
+                }
+
+                Ok(())
+            },
+        )
+    }
+
+    // `bits` are little-endian, double-and-add from the most significant bit.
+    pub fn mul(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        bits: &[AssignedCell<C::Base, C::Base>],
+        p: &EccPoint<C::Base>,
+    ) -> Result<EccPoint<C::Base>, plonk::Error> {
+        let (msb, rest) = bits.split_last().unwrap();
+
+        let mut acc = self.mask(layouter.namespace(|| "mask msb"), msb, p)?;
+        for (i, bit) in rest.iter().enumerate().rev() {
+            acc = self.add(layouter.namespace(|| format!("double-{}", i)), &acc, &acc)?;
+            let addend = self.mask(layouter.namespace(|| format!("mask-{}", i)), bit, p)?;
+            acc = self.add(layouter.namespace(|| format!("add-{}", i)), &acc, &addend)?;
+        }
+
+        Ok(acc)
+    }
+
+    // Sums `bit_i * 2^i * base` with the multiples of `base` in fixed columns.
+    pub fn mul_fixed(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        bits: &[AssignedCell<C::Base, C::Base>],
+        base: C,
+    ) -> Result<EccPoint<C::Base>, plonk::Error> {
+        let mut multiple = base;
+
+        let mut acc: Option<EccPoint<C::Base>> = None;
+        for (i, bit) in bits.iter().enumerate() {
+            let addend =
+                self.mask_fixed(layouter.namespace(|| format!("mask-{}", i)), bit, multiple)?;
+            acc = Some(match acc {
+                None => addend,
+                Some(acc) => {
+                    self.add(layouter.namespace(|| format!("add-{}", i)), &acc, &addend)?
+                }
+            });
+
+            multiple = (multiple + multiple).into();
+        }
+
+        Ok(acc.unwrap())
+    }
+
+    // `bit ? p : identity`
+    fn mask(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        bit: &AssignedCell<C::Base, C::Base>,
+        p: &EccPoint<C::Base>,
+    ) -> Result<EccPoint<C::Base>, plonk::Error> {
+        layouter.assign_region(
+            || "mask",
+            |mut region| {
+                self.config.q_mask.enable(&mut region, 0)?;
+
+                let (x_p, y_p) = self.copy_p(&mut region, p)?;
+                let bit = bit.copy_advice(|| "bit", &mut region, self.config.bit, 0)?;
+                let bit = bit.value().copied();
+
+                let x = region.assign_advice(|| "x_r", self.config.x_r, 0, || bit * x_p)?;
+                let y = region.assign_advice(|| "y_r", self.config.y_r, 0, || bit * y_p)?;
+
+                Ok(EccPoint { x, y })
+            },
+        )
+    }
+
+    // `bit ? base : identity`
+    fn mask_fixed(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        bit: &AssignedCell<C::Base, C::Base>,
+        base: C,
+    ) -> Result<EccPoint<C::Base>, plonk::Error> {
+        let (x_base, y_base) = coords(base);
+
+        layouter.assign_region(
+            || "mask fixed",
+            |mut region| {
+                self.config.q_mask_fixed.enable(&mut region, 0)?;
+
+                region.assign_fixed(|| "x", self.config.fixed_x, 0, || Value::known(x_base))?;
+                region.assign_fixed(|| "y", self.config.fixed_y, 0, || Value::known(y_base))?;
+
+                let bit = bit.copy_advice(|| "bit", &mut region, self.config.bit, 0)?;
+                let bit = bit.value().copied();
+
+                let x = region.assign_advice(
+                    || "x_r",
+                    self.config.x_r,
+                    0,
+                    || bit * Value::known(x_base),
+                )?;
+                let y = region.assign_advice(
+                    || "y_r",
+                    self.config.y_r,
+                    0,
+                    || bit * Value::known(y_base),
+                )?;
+
+                Ok(EccPoint { x, y })
+            },
+        )
+    }
+
+    fn copy_p(
+        &self,
+        region: &mut Region<'_, C::Base>,
+        p: &EccPoint<C::Base>,
+    ) -> Result<(Value<C::Base>, Value<C::Base>), plonk::Error> {
+        let x = p.x.copy_advice(|| "x_p", region, self.config.x_p, 0)?;
+        let y = p.y.copy_advice(|| "y_p", region, self.config.y_p, 0)?;
+        Ok((x.value().copied(), y.value().copied()))
+    }
+
+    fn copy_q(
+        &self,
+        region: &mut Region<'_, C::Base>,
+        q: &EccPoint<C::Base>,
+    ) -> Result<(Value<C::Base>, Value<C::Base>), plonk::Error> {
+        let x = q.x.copy_advice(|| "x_q", region, self.config.x_q, 0)?;
+        let y = q.y.copy_advice(|| "y_q", region, self.config.y_q, 0)?;
+        Ok((x.value().copied(), y.value().copied()))
+    }
+
+    fn assign_lambda_and_r(
+        &self,
+        region: &mut Region<'_, C::Base>,
+        lambda: Value<C::Base>,
+        x_p: Value<C::Base>,
+        y_p: Value<C::Base>,
+        x_q: Value<C::Base>,
+    ) -> Result<EccPoint<C::Base>, plonk::Error> {
+        let x_r = lambda * lambda - x_p - x_q;
+        let y_r = lambda * (x_p - x_r) - y_p;
+
+        region.assign_advice(|| "lambda", self.config.lambda, 0, || lambda)?;
+        let x = region.assign_advice(|| "x_r", self.config.x_r, 0, || x_r)?;
+        let y = region.assign_advice(|| "y_r", self.config.y_r, 0, || y_r)?;
+
+        Ok(EccPoint { x, y })
+    }
+}
@@ -0,0 +1,86 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use halo2_proofs::{
+    halo2curves::bn256::{Bn256, Fr, G1Affine},
+    plonk::{self, create_proof, keygen_pk, keygen_vk, Circuit, ProvingKey, VerifyingKey},
+    poly::kzg::{
+        commitment::{KZGCommitmentScheme, ParamsKZG},
+        multiopen::ProverSHPLONK,
+    },
+    transcript::TranscriptWriterBuffer,
+};
+use halo2_solidity_verifier::{
+    compile_solidity, encode_calldata, BatchOpenScheme, Evm, Keccak256Transcript, SolidityGenerator,
+};
+use rand_core::RngCore;
+
+// The on-chain counterpart of `prover`: KZG commitments over BN254, SHPLONK openings (which the
+// generated verifier knows as BDFG21) and a Keccak256 transcript the contract can replay.
+//
+// The generated verifier reads a single instance column, so only circuits with at most one can
+// be verified this way, e.g. `FiboCircuit` or `is_zero2`'s `FooCircuit`.
+pub type Params = ParamsKZG<Bn256>;
+
+// An insecure setup with a known toxic waste, only fit for tests.
+pub fn setup(k: u32, rng: impl RngCore) -> Params {
+    ParamsKZG::setup(k, rng)
+}
+
+pub fn keygen<C: Circuit<Fr>>(
+    params: &Params,
+    circuit: &C,
+) -> Result<ProvingKey<G1Affine>, plonk::Error> {
+    let vk = keygen_vk(params, circuit)?;
+    keygen_pk(params, vk, circuit)
+}
+
+pub fn prove<C: Circuit<Fr>>(
+    params: &Params,
+    pk: &ProvingKey<G1Affine>,
+    circuit: C,
+    instances: &[Fr],
+    rng: impl RngCore,
+) -> Result<Vec<u8>, plonk::Error> {
+    let mut transcript = Keccak256Transcript::new(vec![]);
+    create_proof::<KZGCommitmentScheme<Bn256>, ProverSHPLONK<'_, Bn256>, _, _, _, _>(
+        params,
+        pk,
+        &[circuit],
+        &[&[instances]],
+        rng,
+        &mut transcript,
+    )?;
+
+    Ok(transcript.finalize())
+}
+
+// Solidity source of a contract verifying proofs against `vk`, with `num_instances` values in
+// the instance column.
+pub fn render_verifier(
+    params: &Params,
+    vk: &VerifyingKey<G1Affine>,
+    num_instances: usize,
+) -> String {
+    SolidityGenerator::new(params, vk, BatchOpenScheme::Bdfg21, num_instances)
+        .render()
+        .unwrap()
+}
+
+// Calldata for the contract from `render_verifier`, with the verifying key embedded in it.
+pub fn calldata(proof: &[u8], instances: &[Fr]) -> Vec<u8> {
+    encode_calldata(None, proof, instances)
+}
+
+// Compiles `verifier` with the local `solc`, deploys it in an in-process EVM and calls it with
+// `calldata`. Returns the gas used by the call if the contract accepted the proof, and `None` if
+// it reverted, as it does on invalid ones. Failing to compile or deploy still panics.
+pub fn verify_in_evm(verifier: &str, calldata: Vec<u8>) -> Option<u64> {
+    let bytecode = compile_solidity(verifier);
+    let mut evm = Evm::default();
+    let address = evm.create(bytecode);
+
+    // `Evm::call` panics when the call reverts.
+    let (gas, output) =
+        panic::catch_unwind(AssertUnwindSafe(|| evm.call(address, calldata))).ok()?;
+    (output == [vec![0; 31], vec![1]].concat()).then_some(gas)
+}
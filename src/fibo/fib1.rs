@@ -0,0 +1,177 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    plonk::{self, Advice, Circuit, Column, ConstraintSystem, FloorPlanner, Instance, Selector},
+    poly::Rotation,
+};
+
+#[derive(Clone)]
+pub struct FiboConfig {
+    col_a: Column<Advice>,
+    col_b: Column<Advice>,
+    col_c: Column<Advice>,
+    sel: Selector,
+    instance: Column<Instance>,
+}
+
+impl FiboConfig {
+    pub fn new<F: Field>(meta: &mut ConstraintSystem<F>) -> Self {
+        let col_a = meta.advice_column();
+        let col_b = meta.advice_column();
+        let col_c = meta.advice_column();
+        let sel = meta.selector();
+        let instance = meta.instance_column();
+
+        meta.enable_equality(col_a);
+        meta.enable_equality(col_b);
+        meta.enable_equality(col_c);
+        meta.enable_equality(instance);
+
+        Self {
+            col_a,
+            col_b,
+            col_c,
+            sel,
+            instance,
+        }
+    }
+}
+
+pub struct FiboChip<F> {
+    config: FiboConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> FiboChip<F> {
+    pub fn new(config: FiboConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>, config: &FiboConfig) {
+        meta.create_gate("fibonacci", |meta| {
+            let sel = meta.query_selector(config.sel);
+
+            let col_a = meta.query_advice(config.col_a, Rotation::cur());
+            let col_b = meta.query_advice(config.col_b, Rotation::cur());
+            let col_c = meta.query_advice(config.col_c, Rotation::cur());
+
+            vec![sel * (col_a + col_b - col_c)]
+        })
+    }
+
+    pub fn assign_init(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Value<F>,
+        b: Value<F>,
+    ) -> Result<(AssignedCell<F, F>, AssignedCell<F, F>), plonk::Error> {
+        layouter.assign_region(
+            || "assign init",
+            |mut region| {
+                self.config.sel.enable(&mut region, 0)?;
+
+                let c = a + b;
+
+                let _cell_a = region.assign_advice(|| "init a", self.config.col_a, 0, || a)?;
+                let cell_b = region.assign_advice(|| "init b", self.config.col_b, 0, || b)?;
+                let cell_c = region.assign_advice(|| "init c", self.config.col_c, 0, || c)?;
+                Ok((cell_b, cell_c))
+            },
+        )
+    }
+
+    pub fn assign(
+        &self,
+        mut layouter: impl Layouter<F>,
+        prev_b: &AssignedCell<F, F>,
+        prev_c: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        layouter.assign_region(
+            || "assign",
+            |mut region| {
+                self.config.sel.enable(&mut region, 0)?;
+
+                let c = prev_b.value().and_then(|a| prev_c.value().map(|b| *a + *b));
+
+                prev_b.copy_advice(|| "copy prev b", &mut region, self.config.col_a, 0)?;
+                prev_c.copy_advice(|| "copy prev c", &mut region, self.config.col_b, 0)?;
+                let cell_c = region.assign_advice(|| "assign c", self.config.col_c, 0, || c)?;
+                Ok(cell_c)
+            },
+        )
+    }
+
+    pub fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        output: AssignedCell<F, F>,
+    ) -> Result<(), plonk::Error> {
+        layouter.constrain_instance(output.cell(), self.config.instance, 0)
+    }
+}
+
+pub struct FiboCircuit<F, P = SimpleFloorPlanner> {
+    pub a: Value<F>,
+    pub b: Value<F>,
+    _marker: PhantomData<P>,
+}
+
+impl<F> FiboCircuit<F> {
+    pub fn new(a: Value<F>, b: Value<F>) -> Self {
+        Self::with_floor_planner(a, b)
+    }
+}
+
+impl<F, P> FiboCircuit<F, P> {
+    pub fn with_floor_planner(a: Value<F>, b: Value<F>) -> Self {
+        Self {
+            a,
+            b,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F: Field, P: FloorPlanner> Circuit<F> for FiboCircuit<F, P> {
+    type Config = FiboConfig;
+    type FloorPlanner = P;
+
+    fn without_witnesses(&self) -> Self {
+        Self::with_floor_planner(Value::unknown(), Value::unknown())
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let config = FiboConfig::new(meta);
+        FiboChip::configure(meta, &config);
+        config
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let fibo_chip = FiboChip::new(config);
+
+        let (mut prev_b, mut prev_c) =
+            fibo_chip.assign_init(layouter.namespace(|| "assign init"), self.a, self.b)?;
+
+        for i in 1..10 {
+            let cell_c = fibo_chip.assign(
+                layouter.namespace(|| format!("assign-{}", i)),
+                &prev_b,
+                &prev_c,
+            )?;
+
+            prev_b = prev_c;
+            prev_c = cell_c;
+        }
+
+        fibo_chip.expose_public(layouter.namespace(|| "expose public"), prev_c)
+    }
+}
@@ -0,0 +1,165 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    plonk::{self, Advice, Circuit, Column, ConstraintSystem, FloorPlanner, Instance, Selector},
+    poly::Rotation,
+};
+
+#[derive(Clone)]
+pub struct FiboConfig {
+    col: Column<Advice>,
+    sel: Selector,
+    instance: Column<Instance>,
+}
+
+impl FiboConfig {
+    pub fn new<F: Field>(meta: &mut ConstraintSystem<F>) -> Self {
+        let col = meta.advice_column();
+        let sel = meta.selector();
+        let instance = meta.instance_column();
+
+        meta.enable_equality(col);
+        meta.enable_equality(instance);
+
+        Self { col, sel, instance }
+    }
+}
+
+pub struct FiboChip<F> {
+    config: FiboConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> FiboChip<F> {
+    pub fn new(config: FiboConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>, config: &FiboConfig) {
+        meta.create_gate("fibonacci", |meta| {
+            let sel = meta.query_selector(config.sel);
+
+            let a = meta.query_advice(config.col, Rotation::prev());
+            let b = meta.query_advice(config.col, Rotation::cur());
+            let c = meta.query_advice(config.col, Rotation::next());
+
+            vec![sel * (a + b - c)]
+        })
+    }
+
+    pub fn assign_init(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Value<F>,
+        b: Value<F>,
+    ) -> Result<(AssignedCell<F, F>, AssignedCell<F, F>), plonk::Error> {
+        layouter.assign_region(
+            || "assign init",
+            |mut region| {
+                self.config.sel.enable(&mut region, 1)?;
+
+                let c = a + b;
+
+                region.assign_advice(|| "a", self.config.col, 0, || a)?;
+                let cell_b = region.assign_advice(|| "b", self.config.col, 1, || b)?;
+                let cell_c = region.assign_advice(|| "c", self.config.col, 2, || c)?;
+
+                Ok((cell_b, cell_c))
+            },
+        )
+    }
+
+    pub fn assign(
+        &self,
+        mut layouter: impl Layouter<F>,
+        prev_b: &AssignedCell<F, F>,
+        prev_c: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        layouter.assign_region(
+            || "assign",
+            |mut region| {
+                self.config.sel.enable(&mut region, 1)?;
+
+                let c = prev_b.value().map(|v| *v) + prev_c.value();
+
+                prev_b.copy_advice(|| "copy b", &mut region, self.config.col, 0)?;
+                prev_c.copy_advice(|| "copy c", &mut region, self.config.col, 1)?;
+                region.assign_advice(|| "assign c", self.config.col, 2, || c)
+            },
+        )
+    }
+
+    pub fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        public: AssignedCell<F, F>,
+    ) -> Result<(), plonk::Error> {
+        layouter.constrain_instance(public.cell(), self.config.instance, 0)
+    }
+}
+
+pub struct FiboCircuit<F, P = SimpleFloorPlanner> {
+    pub a: Value<F>,
+    pub b: Value<F>,
+    _marker: PhantomData<P>,
+}
+
+impl<F> FiboCircuit<F> {
+    pub fn new(a: Value<F>, b: Value<F>) -> Self {
+        Self::with_floor_planner(a, b)
+    }
+}
+
+impl<F, P> FiboCircuit<F, P> {
+    pub fn with_floor_planner(a: Value<F>, b: Value<F>) -> Self {
+        Self {
+            a,
+            b,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F: Field, P: FloorPlanner> Circuit<F> for FiboCircuit<F, P> {
+    type Config = FiboConfig;
+    type FloorPlanner = P;
+
+    fn without_witnesses(&self) -> Self {
+        Self::with_floor_planner(Value::unknown(), Value::unknown())
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let fibo_config = FiboConfig::new(meta);
+        FiboChip::configure(meta, &fibo_config);
+        fibo_config
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let fibo_chip = FiboChip::new(config);
+
+        let (mut prev_b, mut prev_c) =
+            fibo_chip.assign_init(layouter.namespace(|| "init"), self.a, self.b)?;
+
+        for i in 1..10 {
+            let cell_c = fibo_chip.assign(
+                layouter.namespace(|| format!("assign-{}", i)),
+                &prev_b,
+                &prev_c,
+            )?;
+
+            prev_b = prev_c;
+            prev_c = cell_c;
+        }
+
+        fibo_chip.expose_public(layouter.namespace(|| "expose public"), prev_c)
+    }
+}
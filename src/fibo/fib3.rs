@@ -0,0 +1,136 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    plonk::{self, Advice, Circuit, Column, ConstraintSystem, FloorPlanner, Instance, Selector},
+    poly::Rotation,
+};
+
+#[derive(Clone)]
+pub struct FiboConfig {
+    col: Column<Advice>,
+    sel: Selector,
+    instance: Column<Instance>,
+}
+
+impl FiboConfig {
+    pub fn new<F: Field>(meta: &mut ConstraintSystem<F>) -> Self {
+        let col = meta.advice_column();
+        let sel = meta.selector();
+        let instance = meta.instance_column();
+
+        meta.enable_equality(col);
+        meta.enable_equality(instance);
+
+        Self { col, sel, instance }
+    }
+}
+
+pub struct FiboChip {
+    config: FiboConfig,
+}
+
+impl FiboChip {
+    pub fn new(config: FiboConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn configure<F: Field>(meta: &mut ConstraintSystem<F>, config: &FiboConfig) {
+        meta.create_gate("fibonacci", |meta| {
+            let sel = meta.query_selector(config.sel);
+            let a = meta.query_advice(config.col, Rotation::cur());
+            let b = meta.query_advice(config.col, Rotation::next());
+            let c = meta.query_advice(config.col, Rotation(2));
+
+            vec![sel * (a + b - c)]
+        })
+    }
+
+    pub fn assign<F: Field>(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Value<F>,
+        b: Value<F>,
+        nrows: usize,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        layouter.assign_region(
+            || "entire table",
+            |mut region| {
+                self.config.sel.enable(&mut region, 0)?;
+                region.assign_advice(|| "init a", self.config.col, 0, || a)?;
+                let mut prev_b = region.assign_advice(|| "init b", self.config.col, 1, || b)?;
+                let mut prev_c = region.assign_advice(|| "init c", self.config.col, 2, || a + b)?;
+
+                for i in 3..nrows {
+                    self.config.sel.enable(&mut region, i - 2)?;
+                    let v = prev_b.value().cloned() + prev_c.value();
+                    let cell_c =
+                        region.assign_advice(|| format!("row-{}", i), self.config.col, i, || v)?;
+
+                    prev_b = prev_c;
+                    prev_c = cell_c;
+                }
+
+                Ok(prev_c)
+            },
+        )
+    }
+
+    pub fn expose_public<F: Field>(
+        &self,
+        mut layouter: impl Layouter<F>,
+        final_cell: AssignedCell<F, F>,
+    ) -> Result<(), plonk::Error> {
+        layouter.constrain_instance(final_cell.cell(), self.config.instance, 0)
+    }
+}
+
+pub struct FiboCircuit<F, P = SimpleFloorPlanner> {
+    pub a: Value<F>,
+    pub b: Value<F>,
+    _marker: PhantomData<P>,
+}
+
+impl<F> FiboCircuit<F> {
+    pub fn new(a: Value<F>, b: Value<F>) -> Self {
+        Self::with_floor_planner(a, b)
+    }
+}
+
+impl<F, P> FiboCircuit<F, P> {
+    pub fn with_floor_planner(a: Value<F>, b: Value<F>) -> Self {
+        Self {
+            a,
+            b,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F: Field, P: FloorPlanner> Circuit<F> for FiboCircuit<F, P> {
+    type Config = FiboConfig;
+    type FloorPlanner = P;
+
+    fn without_witnesses(&self) -> Self {
+        Self::with_floor_planner(Value::unknown(), Value::unknown())
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let config = FiboConfig::new(meta);
+        FiboChip::configure(meta, &config);
+        config
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let fibo_chip = FiboChip::new(config);
+
+        let final_cell = fibo_chip.assign(layouter.namespace(|| "assign"), self.a, self.b, 10)?;
+
+        fibo_chip.expose_public(layouter.namespace(|| "expose public"), final_cell)
+    }
+}
@@ -0,0 +1,11 @@
+use halo2_proofs::arithmetic::Field;
+
+pub mod fib1;
+pub mod fib2;
+pub mod fib3;
+
+// The `n`th term of the sequence starting with `a, b`. fib1 and fib2 expose the 11th term, fib3
+// the 9th.
+pub fn fibonacci<F: Field>(a: F, b: F, n: usize) -> F {
+    (0..n).fold((a, b), |(a, b), _| (b, a + b)).0
+}
@@ -0,0 +1,475 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, Region, Value},
+    plonk::{self, Advice, Column, ConstraintSystem, Expression, Selector},
+    poly::Rotation,
+};
+
+use crate::range_check::{
+    decompose::{DecomposeChip, DecomposeConfig},
+    example2::RangeCheckTable,
+};
+
+// Every value is kept in (-2^63, 2^63), so `value + 2^63` fits in 8 byte limbs.
+const NUM_LIMBS: usize = 8;
+
+// A signed fixed-point number `raw / SCALE`, used as the native reference of `FixedPointChip`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FixedPoint<const SCALE: u64> {
+    raw: i64,
+}
+
+impl<const SCALE: u64> FixedPoint<SCALE> {
+    pub fn from_raw(raw: i64) -> Self {
+        Self { raw }
+    }
+
+    pub fn raw(&self) -> i64 {
+        self.raw
+    }
+
+    pub fn from_f64(v: f64) -> Self {
+        Self::from_raw((v * SCALE as f64).round() as i64)
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.raw as f64 / SCALE as f64
+    }
+
+    pub fn relu(self) -> Self {
+        Self::from_raw(self.raw.max(0))
+    }
+
+    pub fn to_field<F: PrimeField>(self) -> F {
+        i64_to_fe(self.raw)
+    }
+
+    pub fn from_field<F: PrimeField>(v: &F) -> Self {
+        Self::from_raw(fe_to_i64(v))
+    }
+}
+
+impl<const SCALE: u64> Add for FixedPoint<SCALE> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::from_raw(self.raw + other.raw)
+    }
+}
+
+impl<const SCALE: u64> Sub for FixedPoint<SCALE> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::from_raw(self.raw - other.raw)
+    }
+}
+
+// Rounds towards negative infinity.
+impl<const SCALE: u64> Mul for FixedPoint<SCALE> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::from_raw((self.raw as i128 * other.raw as i128).div_euclid(SCALE as i128) as i64)
+    }
+}
+
+// Rounds towards negative infinity. Only positive divisors are supported.
+impl<const SCALE: u64> Div for FixedPoint<SCALE> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        assert!(other.raw > 0, "divisor must be positive");
+        Self::from_raw((self.raw as i128 * SCALE as i128).div_euclid(other.raw as i128) as i64)
+    }
+}
+
+pub fn i64_to_fe<F: PrimeField>(v: i64) -> F {
+    if v >= 0 {
+        F::from(v as u64)
+    } else {
+        -F::from(v.unsigned_abs())
+    }
+}
+
+pub fn fe_to_i64<F: PrimeField>(v: &F) -> i64 {
+    let is_small = |v: &F| v.to_repr().as_ref()[8..].iter().all(|b| *b == 0);
+
+    if is_small(v) {
+        crate::fe_to_u64(v) as i64
+    } else {
+        -(crate::fe_to_u64(&-*v) as i64)
+    }
+}
+
+#[derive(Clone)]
+pub struct FixedPointConfig<F> {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    c: Column<Advice>,
+    x: Column<Advice>,
+    y: Column<Advice>,
+    z: Column<Advice>,
+
+    q_bound: Selector,
+    q_add: Selector,
+    q_sub: Selector,
+    q_mul: Selector,
+    q_div: Selector,
+    q_lt: Selector,
+    q_relu: Selector,
+
+    decompose_config: DecomposeConfig<F>,
+}
+
+pub struct FixedPointChip<F, const SCALE: u64> {
+    config: FixedPointConfig<F>,
+}
+
+impl<F: PrimeField, const SCALE: u64> FixedPointChip<F, SCALE> {
+    pub fn new(config: FixedPointConfig<F>) -> Self {
+        Self { config }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> FixedPointConfig<F> {
+        let [a, b, c, x, y, z] = [(); 6].map(|_| meta.advice_column());
+        for col in [a, b, c, x, y, z] {
+            meta.enable_equality(col);
+        }
+
+        let [q_bound, q_add, q_sub, q_mul, q_div, q_lt, q_relu] = [(); 7].map(|_| meta.selector());
+
+        let table = RangeCheckTable::new(meta);
+        let decompose_config = DecomposeChip::configure(meta, table);
+
+        let one = || Expression::Constant(F::ONE);
+        let scale = || Expression::Constant(F::from(SCALE));
+        let half_bound = || Expression::Constant(F::from(1u64 << 63));
+        let bound = || Expression::Constant(F::from_u128(1 << 64));
+
+        // x, y and z are the range checked helper cells of each row.
+        //
+        //  op   |   x   |      y      |     z
+        //  mul  |  rem  | SCALE-1-rem | c + 2^63
+        //  div  |  rem  |   b-1-rem   | c + 2^63
+        //  lt   |  lo   |             |
+        //  relu |  lo   |   is_neg    |
+        meta.create_gate("fixed point", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let c = meta.query_advice(c, Rotation::cur());
+            let x = meta.query_advice(x, Rotation::cur());
+            let y = meta.query_advice(y, Rotation::cur());
+            let z = meta.query_advice(z, Rotation::cur());
+
+            let q_bound = meta.query_selector(q_bound);
+            let q_add = meta.query_selector(q_add);
+            let q_sub = meta.query_selector(q_sub);
+            let q_mul = meta.query_selector(q_mul);
+            let q_div = meta.query_selector(q_div);
+            let q_lt = meta.query_selector(q_lt);
+            let q_relu = meta.query_selector(q_relu);
+
+            vec![
+                ("bound", q_bound * (z - c.clone() - half_bound())),
+                ("add", q_add * (a.clone() + b.clone() - c.clone())),
+                ("sub", q_sub * (a.clone() - b.clone() - c.clone())),
+                (
+                    "mul",
+                    q_mul.clone() * (a.clone() * b.clone() - c.clone() * scale() - x.clone()),
+                ),
+                (
+                    "mul rem",
+                    q_mul * (y.clone() - (scale() - one() - x.clone())),
+                ),
+                (
+                    "div",
+                    q_div.clone() * (a.clone() * scale() - c.clone() * b.clone() - x.clone()),
+                ),
+                (
+                    "div rem",
+                    q_div * (y.clone() - (b.clone() - one() - x.clone())),
+                ),
+                ("lt bool", q_lt.clone() * c.clone() * (one() - c.clone())),
+                (
+                    "lt",
+                    q_lt * (a.clone() - b + c.clone() * bound() - x.clone()),
+                ),
+                (
+                    "relu bool",
+                    q_relu.clone() * y.clone() * (one() - y.clone()),
+                ),
+                (
+                    "relu sign",
+                    q_relu.clone() * (a.clone() + y.clone() * bound() - x),
+                ),
+                ("relu", q_relu * (c - (one() - y) * a)),
+            ]
+        });
+
+        FixedPointConfig {
+            a,
+            b,
+            c,
+            x,
+            y,
+            z,
+            q_bound,
+            q_add,
+            q_sub,
+            q_mul,
+            q_div,
+            q_lt,
+            q_relu,
+            decompose_config,
+        }
+    }
+
+    pub fn load_table(&self, layouter: impl Layouter<F>) -> Result<(), plonk::Error> {
+        self.decompose_chip().load_table(layouter)
+    }
+
+    pub fn load_private(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: Value<FixedPoint<SCALE>>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        let (c, z) = layouter.assign_region(
+            || "load private",
+            |mut region| {
+                let c = region.assign_advice(
+                    || "value",
+                    self.config.c,
+                    0,
+                    || value.map(|v| v.to_field::<F>()),
+                )?;
+                let z = self.assign_bound(&mut region, &c)?;
+                Ok((c, z))
+            },
+        )?;
+
+        self.range_check(layouter, [z])?;
+
+        Ok(c)
+    }
+
+    pub fn add(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        self.add_sub(layouter, a, b, self.config.q_add, |a, b| a + b)
+    }
+
+    pub fn sub(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        self.add_sub(layouter, a, b, self.config.q_sub, |a, b| a - b)
+    }
+
+    pub fn mul(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        self.mul_div(layouter, a, b, self.config.q_mul, |a, b| {
+            let product = a.raw() as i128 * b.raw() as i128;
+            let rem = product.rem_euclid(SCALE as i128) as i64;
+            (a * b, rem, SCALE as i64 - 1 - rem)
+        })
+    }
+
+    // The divisor must be positive, otherwise the circuit is not satisfied.
+    pub fn div(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        self.mul_div(layouter, a, b, self.config.q_div, |a, b| {
+            let b = b.raw().max(1);
+            let rem = (a.raw() as i128 * SCALE as i128).rem_euclid(b as i128) as i64;
+            (a / FixedPoint::from_raw(b), rem, b - 1 - rem)
+        })
+    }
+
+    // Returns 1 if `a < b`, 0 otherwise.
+    pub fn lt(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        let (c, lo) = layouter.assign_region(
+            || "lt",
+            |mut region| {
+                self.config.q_lt.enable(&mut region, 0)?;
+
+                let a = a.copy_advice(|| "a", &mut region, self.config.a, 0)?;
+                let b = b.copy_advice(|| "b", &mut region, self.config.b, 0)?;
+
+                let lt = a
+                    .value()
+                    .zip(b.value())
+                    .map(|(a, b)| fe_to_i64(a) < fe_to_i64(b));
+                let c = region.assign_advice(
+                    || "lt",
+                    self.config.c,
+                    0,
+                    || lt.map(|lt| F::from(lt as u64)),
+                )?;
+
+                let lo =
+                    a.value().copied() - b.value() + c.value().map(|c| *c * F::from_u128(1 << 64));
+                let lo = region.assign_advice(|| "lo", self.config.x, 0, || lo)?;
+
+                Ok((c, lo))
+            },
+        )?;
+
+        self.range_check(layouter, [lo])?;
+
+        Ok(c)
+    }
+
+    pub fn relu(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        let (c, lo) = layouter.assign_region(
+            || "relu",
+            |mut region| {
+                self.config.q_relu.enable(&mut region, 0)?;
+
+                let a = a.copy_advice(|| "a", &mut region, self.config.a, 0)?;
+
+                let is_neg = a.value().map(|a| fe_to_i64(a) < 0);
+                let is_neg = region.assign_advice(
+                    || "is neg",
+                    self.config.y,
+                    0,
+                    || is_neg.map(|is_neg| F::from(is_neg as u64)),
+                )?;
+
+                let lo = a.value().copied() + is_neg.value().map(|n| *n * F::from_u128(1 << 64));
+                let lo = region.assign_advice(|| "lo", self.config.x, 0, || lo)?;
+
+                let c = a
+                    .value()
+                    .map(|a| FixedPoint::<SCALE>::from_field(a).relu().to_field::<F>());
+                let c = region.assign_advice(|| "relu", self.config.c, 0, || c)?;
+
+                Ok((c, lo))
+            },
+        )?;
+
+        self.range_check(layouter, [lo])?;
+
+        Ok(c)
+    }
+
+    fn add_sub(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+        q_op: Selector,
+        op: impl Fn(F, F) -> F,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        let (c, z) = layouter.assign_region(
+            || "add sub",
+            |mut region| {
+                q_op.enable(&mut region, 0)?;
+
+                let a = a.copy_advice(|| "a", &mut region, self.config.a, 0)?;
+                let b = b.copy_advice(|| "b", &mut region, self.config.b, 0)?;
+
+                let c = a.value().zip(b.value()).map(|(a, b)| op(*a, *b));
+                let c = region.assign_advice(|| "c", self.config.c, 0, || c)?;
+                let z = self.assign_bound(&mut region, &c)?;
+
+                Ok((c, z))
+            },
+        )?;
+
+        self.range_check(layouter, [z])?;
+
+        Ok(c)
+    }
+
+    // `op` returns the result together with the values of the x and y cells.
+    fn mul_div(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+        q_op: Selector,
+        op: impl Fn(FixedPoint<SCALE>, FixedPoint<SCALE>) -> (FixedPoint<SCALE>, i64, i64),
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        let (c, x, y, z) = layouter.assign_region(
+            || "mul div",
+            |mut region| {
+                q_op.enable(&mut region, 0)?;
+
+                let a = a.copy_advice(|| "a", &mut region, self.config.a, 0)?;
+                let b = b.copy_advice(|| "b", &mut region, self.config.b, 0)?;
+
+                let res = a.value().zip(b.value()).map(|(a, b)| {
+                    let (c, x, y) = op(FixedPoint::from_field(a), FixedPoint::from_field(b));
+                    (c.to_field::<F>(), i64_to_fe::<F>(x), i64_to_fe::<F>(y))
+                });
+
+                let c = region.assign_advice(|| "c", self.config.c, 0, || res.map(|r| r.0))?;
+                let x = region.assign_advice(|| "rem", self.config.x, 0, || res.map(|r| r.1))?;
+                let y =
+                    region.assign_advice(|| "rem bound", self.config.y, 0, || res.map(|r| r.2))?;
+                let z = self.assign_bound(&mut region, &c)?;
+
+                Ok((c, x, y, z))
+            },
+        )?;
+
+        self.range_check(layouter, [x, y, z])?;
+
+        Ok(c)
+    }
+
+    fn assign_bound(
+        &self,
+        region: &mut Region<'_, F>,
+        c: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        self.config.q_bound.enable(region, 0)?;
+
+        let z = c.value().map(|c| *c + F::from(1u64 << 63));
+        region.assign_advice(|| "bound", self.config.z, 0, || z)
+    }
+
+    fn range_check<const N: usize>(
+        &self,
+        mut layouter: impl Layouter<F>,
+        cells: [AssignedCell<F, F>; N],
+    ) -> Result<(), plonk::Error> {
+        let decompose_chip = self.decompose_chip();
+        for (i, cell) in cells.iter().enumerate() {
+            decompose_chip.range_check(
+                layouter.namespace(|| format!("range check {}", i)),
+                cell,
+                NUM_LIMBS,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn decompose_chip(&self) -> DecomposeChip<F> {
+        DecomposeChip::new(self.config.decompose_config.clone())
+    }
+}
@@ -0,0 +1,364 @@
+use std::fmt;
+
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::Value,
+    dev::{MockProver, VerifyFailure},
+    halo2curves::pasta::Fp,
+    plonk::{self, Circuit},
+};
+use rand_core::OsRng;
+use serde::{
+    de::{self, DeserializeOwned, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    fibo::{self, fib1, fib2, fib3},
+    is_zero, is_zero2, prover,
+    range_check::{example1, example2},
+};
+
+// A field element, given in JSON as a decimal or `0x` prefixed hex string, or as a number, and
+// written back in decimal. Values that aren't canonical (`>= p`) are rejected rather than reduced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldElement(pub Fp);
+
+impl FieldElement {
+    pub fn parse(s: &str) -> Option<Self> {
+        let (digits, radix) = match s.strip_prefix("0x") {
+            Some(hex) => (hex, 16),
+            None => (s, 10),
+        };
+        if digits.is_empty() {
+            return None;
+        }
+
+        // Little-endian u64 limbs.
+        let mut limbs = [0u64; 4];
+        for c in digits.chars() {
+            let mut carry = c.to_digit(radix)? as u128;
+            for limb in limbs.iter_mut() {
+                let v = *limb as u128 * radix as u128 + carry;
+                *limb = v as u64;
+                carry = v >> 64;
+            }
+            if carry != 0 {
+                return None;
+            }
+        }
+
+        let mut repr = <Fp as PrimeField>::Repr::default();
+        for (bytes, limb) in repr.as_mut().chunks_mut(8).zip(limbs) {
+            bytes.copy_from_slice(&limb.to_le_bytes());
+        }
+        Option::from(Fp::from_repr(repr)).map(Self)
+    }
+}
+
+impl fmt::Display for FieldElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut limbs: Vec<u64> = self
+            .0
+            .to_repr()
+            .as_ref()
+            .chunks(8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+            .collect();
+
+        let mut digits = vec![];
+        loop {
+            let mut rem = 0u128;
+            for limb in limbs.iter_mut().rev() {
+                let v = (rem << 64) | *limb as u128;
+                *limb = (v / 10) as u64;
+                rem = v % 10;
+            }
+            digits.push(char::from(b'0' + rem as u8));
+
+            if limbs.iter().all(|limb| *limb == 0) {
+                break;
+            }
+        }
+
+        write!(f, "{}", digits.iter().rev().collect::<String>())
+    }
+}
+
+impl Serialize for FieldElement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldElement {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldElementVisitor;
+
+        impl<'de> Visitor<'de> for FieldElementVisitor {
+            type Value = FieldElement;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a field element as a decimal or hex string")
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(FieldElement(Fp::from(v)))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                FieldElement::parse(v)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_any(FieldElementVisitor)
+    }
+}
+
+// A circuit that can be run from a JSON input file:
+//
+// { "private": { "a": "1", "b": "0x1" }, "public": { "output": "144" } }
+//
+// `public` can be left out, in which case it's computed natively from `private`.
+pub trait Example {
+    type Private: DeserializeOwned;
+    type Public: Serialize + DeserializeOwned;
+    type Circuit: Circuit<Fp>;
+
+    const K: u32;
+
+    fn circuit(private: &Self::Private) -> Self::Circuit;
+
+    fn public(private: &Self::Private) -> Self::Public;
+
+    fn instances(public: &Self::Public) -> Vec<Vec<Fp>>;
+}
+
+#[derive(Deserialize)]
+struct Input<P, Q> {
+    private: P,
+    public: Option<Q>,
+}
+
+#[derive(Serialize)]
+struct Output<Q> {
+    public: Q,
+}
+
+#[derive(Debug)]
+pub enum RunError {
+    Json(serde_json::Error),
+    Plonk(plonk::Error),
+    Unsatisfied(Vec<VerifyFailure>),
+}
+
+impl From<serde_json::Error> for RunError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Json(e)
+    }
+}
+
+impl From<plonk::Error> for RunError {
+    fn from(e: plonk::Error) -> Self {
+        Self::Plonk(e)
+    }
+}
+
+pub enum Mode {
+    Mock,
+    Prove,
+    // Byte-identical proofs across runs, see `prover::prove_seeded`.
+    ProveSeeded(u64),
+}
+
+// Runs the circuit on the JSON `input`, with the mock prover or with real proving and
+// verification, and returns its public inputs as JSON.
+pub fn run<E: Example>(input: &str, mode: Mode) -> Result<String, RunError> {
+    let input: Input<E::Private, E::Public> = serde_json::from_str(input)?;
+    let public = input.public.unwrap_or_else(|| E::public(&input.private));
+    let instances = E::instances(&public);
+    let circuit = E::circuit(&input.private);
+
+    if let Mode::Mock = mode {
+        MockProver::run(E::K, &circuit, instances)?
+            .verify()
+            .map_err(RunError::Unsatisfied)?;
+    } else {
+        let instances: Vec<_> = instances.iter().map(|column| column.as_slice()).collect();
+        let params = prover::setup(E::K);
+        let pk = prover::keygen(&params, &circuit.without_witnesses())?;
+        let proof = match mode {
+            Mode::ProveSeeded(seed) => {
+                prover::prove_seeded(&params, &pk, circuit, &instances, seed)?
+            }
+            _ => prover::prove(&params, &pk, circuit, &instances, OsRng)?,
+        };
+        prover::verify(&params, pk.get_vk(), &proof, &instances)?;
+    }
+
+    Ok(serde_json::to_string_pretty(&Output { public })?)
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct NoPublic {}
+
+#[derive(Deserialize)]
+pub struct FiboPrivate {
+    pub a: FieldElement,
+    pub b: FieldElement,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FiboPublic {
+    pub output: FieldElement,
+}
+
+macro_rules! fibo_example {
+    ($name:ident, $module:ident, $k:expr, $n:expr) => {
+        pub struct $name;
+
+        impl Example for $name {
+            type Private = FiboPrivate;
+            type Public = FiboPublic;
+            type Circuit = $module::FiboCircuit<Fp>;
+
+            const K: u32 = $k;
+
+            fn circuit(private: &FiboPrivate) -> Self::Circuit {
+                $module::FiboCircuit::new(Value::known(private.a.0), Value::known(private.b.0))
+            }
+
+            fn public(private: &FiboPrivate) -> FiboPublic {
+                FiboPublic {
+                    output: FieldElement(fibo::fibonacci(private.a.0, private.b.0, $n)),
+                }
+            }
+
+            fn instances(public: &FiboPublic) -> Vec<Vec<Fp>> {
+                vec![vec![public.output.0]]
+            }
+        }
+    };
+}
+
+fibo_example!(Fib1, fib1, 4, 11);
+fibo_example!(Fib2, fib2, 6, 11);
+fibo_example!(Fib3, fib3, 4, 9);
+
+#[derive(Deserialize)]
+pub struct FooPrivate {
+    pub a: FieldElement,
+    pub b: FieldElement,
+    pub c: FieldElement,
+}
+
+impl FooPrivate {
+    fn witnesses(&self) -> (Value<Fp>, Value<Fp>, Value<Fp>) {
+        (
+            Value::known(self.a.0),
+            Value::known(self.b.0),
+            Value::known(self.c.0),
+        )
+    }
+
+    // `if a == b { c } else { a - b }`
+    fn output(&self) -> Fp {
+        if self.a == self.b {
+            self.c.0
+        } else {
+            self.a.0 - self.b.0
+        }
+    }
+}
+
+// `use_is_zero`, where the output isn't public.
+pub struct IsZero;
+
+impl Example for IsZero {
+    type Private = FooPrivate;
+    type Public = NoPublic;
+    type Circuit = is_zero::example::FooCircuit<Fp>;
+
+    const K: u32 = 4;
+
+    fn circuit(private: &FooPrivate) -> Self::Circuit {
+        let (a, b, c) = private.witnesses();
+        is_zero::example::FooCircuit::new(a, b, c)
+    }
+
+    fn public(_: &FooPrivate) -> NoPublic {
+        NoPublic {}
+    }
+
+    fn instances(_: &NoPublic) -> Vec<Vec<Fp>> {
+        vec![]
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FooPublic {
+    pub output: FieldElement,
+}
+
+// `use_is_zero2`, with the output as public input.
+pub struct IsZero2;
+
+impl Example for IsZero2 {
+    type Private = FooPrivate;
+    type Public = FooPublic;
+    type Circuit = is_zero2::example::FooCircuit<Fp>;
+
+    const K: u32 = 4;
+
+    fn circuit(private: &FooPrivate) -> Self::Circuit {
+        let (a, b, c) = private.witnesses();
+        is_zero2::example::FooCircuit::new(a, b, c)
+    }
+
+    fn public(private: &FooPrivate) -> FooPublic {
+        FooPublic {
+            output: FieldElement(private.output()),
+        }
+    }
+
+    fn instances(public: &FooPublic) -> Vec<Vec<Fp>> {
+        vec![vec![public.output.0]]
+    }
+}
+
+#[derive(Deserialize)]
+pub struct RangeCheckPrivate {
+    pub value: FieldElement,
+}
+
+macro_rules! range_check_example {
+    ($name:ident, $module:ident, $range:expr, $k:expr) => {
+        pub struct $name;
+
+        impl Example for $name {
+            type Private = RangeCheckPrivate;
+            type Public = NoPublic;
+            type Circuit = $module::RangeCheckCircuit<Fp, $range>;
+
+            const K: u32 = $k;
+
+            fn circuit(private: &RangeCheckPrivate) -> Self::Circuit {
+                $module::RangeCheckCircuit::new(Value::known(private.value.0))
+            }
+
+            fn public(_: &RangeCheckPrivate) -> NoPublic {
+                NoPublic {}
+            }
+
+            fn instances(_: &NoPublic) -> Vec<Vec<Fp>> {
+                vec![]
+            }
+        }
+    };
+}
+
+range_check_example!(RangeCheck, example1, 8, 4);
+// Just above `LOOKUP_THRESHOLD`, so the lookup is used, while the expression example2 also
+// configures keeps `--prove` within an extended domain of `2^18`.
+range_check_example!(RangeCheck2, example2, 257, 9);
@@ -0,0 +1,71 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter},
+    plonk::{self, Advice, Column, ConstraintSystem, Constraints, Expression, Selector},
+    poly::Rotation,
+};
+
+#[derive(Clone)]
+pub struct InverseConfig {
+    value: Column<Advice>,
+    value_inv: Column<Advice>,
+    sel: Selector,
+}
+
+// Unlike `IsZeroChip`, which accepts any `value_inv` for a zero value, this chip is not satisfied
+// for zero.
+pub struct InverseChip<F> {
+    config: InverseConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> InverseChip<F> {
+    pub fn new(config: InverseConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> InverseConfig {
+        let value = meta.advice_column();
+        let value_inv = meta.advice_column();
+        let sel = meta.selector();
+
+        meta.enable_equality(value);
+        meta.enable_equality(value_inv);
+
+        meta.create_gate("inverse", |meta| {
+            let sel = meta.query_selector(sel);
+            let value = meta.query_advice(value, Rotation::cur());
+            let value_inv = meta.query_advice(value_inv, Rotation::cur());
+
+            Constraints::with_selector(sel, [value * value_inv - Expression::Constant(F::ONE)])
+        });
+
+        InverseConfig {
+            value,
+            value_inv,
+            sel,
+        }
+    }
+
+    pub fn inverse(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        layouter.assign_region(
+            || "inverse",
+            |mut region| {
+                self.config.sel.enable(&mut region, 0)?;
+
+                let value = value.copy_advice(|| "value", &mut region, self.config.value, 0)?;
+                let value_inv = value.value().map(|v| v.invert().unwrap_or(F::ZERO));
+                region.assign_advice(|| "value inv", self.config.value_inv, 0, || value_inv)
+            },
+        )
+    }
+}
@@ -5,6 +5,8 @@ use halo2_proofs::{
     poly::Rotation,
 };
 
+pub mod example;
+
 #[derive(Clone)]
 pub struct IsZeroConfig<F> {
     value_inv: Column<Advice>,
@@ -58,9 +60,19 @@ impl<F: Field> IsZeroChip<F> {
         }
     }
 
-    pub fn assign(&self, region: &mut Region<'_, F>, value: F) -> Result<(), plonk::Error> {
-        let value_inv = Value::known(value.invert().unwrap_or(F::ZERO));
-        region.assign_advice(|| "assign invert", self.config.value_inv, 0, || value_inv)?;
+    pub fn assign(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: usize,
+        value: Value<F>,
+    ) -> Result<(), plonk::Error> {
+        let value_inv = value.map(|value| value.invert().unwrap_or(F::ZERO));
+        region.assign_advice(
+            || "assign invert",
+            self.config.value_inv,
+            offset,
+            || value_inv,
+        )?;
         Ok(())
     }
 }
@@ -0,0 +1,149 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    plonk::{self, Advice, Circuit, Column, ConstraintSystem, Expression, FloorPlanner, Selector},
+    poly::Rotation,
+};
+
+use crate::is_zero::{IsZeroChip, IsZeroConfig};
+
+#[derive(Clone)]
+pub struct FooConfig<F> {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    c: Column<Advice>,
+    output: Column<Advice>,
+    sel: Selector,
+    is_zero_config: IsZeroConfig<F>,
+}
+
+pub struct FooChip<F> {
+    config: FooConfig<F>,
+}
+
+impl<F: Field> FooChip<F> {
+    pub fn new(config: FooConfig<F>) -> Self {
+        Self { config }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>, config: &FooConfig<F>) {
+        meta.create_gate("if a == b {c} else {a - b}", |vcells| {
+            let a = vcells.query_advice(config.a, Rotation::cur());
+            let b = vcells.query_advice(config.b, Rotation::cur());
+            let c = vcells.query_advice(config.c, Rotation::cur());
+            let output = vcells.query_advice(config.output, Rotation::cur());
+            let sel = vcells.query_selector(config.sel);
+
+            vec![
+                sel.clone() * config.is_zero_config.expr() * (c - output.clone()),
+                sel * (Expression::Constant(F::ONE) - config.is_zero_config.expr())
+                    * (a - b - output),
+            ]
+        });
+    }
+
+    pub fn assign(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Value<F>,
+        b: Value<F>,
+        c: Value<F>,
+    ) -> Result<(), plonk::Error> {
+        let is_zero_chip = IsZeroChip::new(self.config.is_zero_config.clone());
+
+        layouter.assign_region(
+            || "foo",
+            |mut region| {
+                let output = a
+                    .zip(b)
+                    .zip(c)
+                    .map(|((a, b), c)| if a == b { c } else { a - b });
+
+                self.config.sel.enable(&mut region, 0)?;
+
+                region.assign_advice(|| "a", self.config.a, 0, || a)?;
+                region.assign_advice(|| "b", self.config.b, 0, || b)?;
+                region.assign_advice(|| "c", self.config.c, 0, || c)?;
+                region.assign_advice(|| "output", self.config.output, 0, || output)?;
+
+                is_zero_chip.assign(&mut region, 0, a - b)?;
+
+                Ok(())
+            },
+        )
+    }
+}
+
+pub struct FooCircuit<F, P = SimpleFloorPlanner> {
+    pub a: Value<F>,
+    pub b: Value<F>,
+    pub c: Value<F>,
+    _marker: PhantomData<P>,
+}
+
+impl<F> FooCircuit<F> {
+    pub fn new(a: Value<F>, b: Value<F>, c: Value<F>) -> Self {
+        Self::with_floor_planner(a, b, c)
+    }
+}
+
+impl<F, P> FooCircuit<F, P> {
+    pub fn with_floor_planner(a: Value<F>, b: Value<F>, c: Value<F>) -> Self {
+        Self {
+            a,
+            b,
+            c,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F: Field, P: FloorPlanner> Circuit<F> for FooCircuit<F, P> {
+    type Config = FooConfig<F>;
+    type FloorPlanner = P;
+
+    fn without_witnesses(&self) -> Self {
+        Self::with_floor_planner(Value::unknown(), Value::unknown(), Value::unknown())
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let c = meta.advice_column();
+        let output = meta.advice_column();
+        let sel = meta.selector();
+
+        let is_zero_config = IsZeroChip::configure(
+            meta,
+            |meta| meta.query_selector(sel),
+            |meta| meta.query_advice(a, Rotation::cur()) - meta.query_advice(b, Rotation::cur()),
+        );
+
+        let foo_config = FooConfig {
+            a,
+            b,
+            c,
+            sel,
+            output,
+            is_zero_config,
+        };
+
+        FooChip::configure(meta, &foo_config);
+
+        foo_config
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let foo_chip = FooChip::new(config);
+
+        foo_chip.assign(layouter.namespace(|| "assign"), self.a, self.b, self.c)?;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 use std::marker::PhantomData;
 
+pub mod example;
+
 use halo2_proofs::{
     arithmetic::Field,
     circuit::{Region, Value},
@@ -56,13 +58,12 @@ impl<F: Field> IsZero2Chip<F> {
         }
     }
 
-    pub fn assign(&self, region: &mut Region<'_, F>, value: F) -> Result<(), plonk::Error> {
-        region.assign_advice(
-            || "value inv",
-            self.config.value_inv,
-            0,
-            || Value::known(value),
-        )?;
+    pub fn assign(
+        &self,
+        region: &mut Region<'_, F>,
+        value_inv: Value<F>,
+    ) -> Result<(), plonk::Error> {
+        region.assign_advice(|| "value inv", self.config.value_inv, 0, || value_inv)?;
         Ok(())
     }
 }
@@ -0,0 +1,169 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    plonk::{
+        self, Advice, Circuit, Column, ConstraintSystem, Constraints, Expression, FloorPlanner,
+        Instance, Selector,
+    },
+    poly::Rotation,
+};
+
+use crate::is_zero2::{IsZero2Chip, IsZero2Config};
+
+#[derive(Clone)]
+pub struct FooConfig<F> {
+    col: Column<Advice>,
+    sel: Selector,
+    instance: Column<Instance>,
+
+    is_zero_config: IsZero2Config<F>,
+}
+
+pub struct FooChip<F> {
+    config: FooConfig<F>,
+}
+
+impl<F: Field> FooChip<F> {
+    pub fn new(config: FooConfig<F>) -> Self {
+        Self { config }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> FooConfig<F> {
+        let col = meta.advice_column();
+        let sel = meta.selector();
+        let instance = meta.instance_column();
+
+        meta.enable_equality(col);
+        meta.enable_equality(instance);
+
+        let is_zero_config = IsZero2Chip::configure(
+            meta,
+            |meta| meta.query_selector(sel),
+            |meta| {
+                meta.query_advice(col, Rotation::cur()) - meta.query_advice(col, Rotation::next())
+            },
+        );
+
+        let config = FooConfig {
+            col,
+            sel,
+            instance,
+            is_zero_config,
+        };
+
+        meta.create_gate("foo", |meta| {
+            let sel = meta.query_selector(sel);
+
+            let a = meta.query_advice(config.col, Rotation::cur());
+            let b = meta.query_advice(config.col, Rotation::next());
+            let c = meta.query_advice(config.col, Rotation(2));
+            let instance = meta.query_instance(config.instance, Rotation::cur());
+
+            Constraints::with_selector(
+                sel,
+                [
+                    (
+                        "a == b",
+                        config.is_zero_config.expr().clone() * (c - instance.clone()),
+                    ),
+                    (
+                        "a != b",
+                        (config.is_zero_config.expr().clone() - Expression::Constant(F::ONE))
+                            * (a - b - instance),
+                    ),
+                ],
+            )
+        });
+
+        config
+    }
+
+    pub fn assign(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Value<F>,
+        b: Value<F>,
+        c: Value<F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        layouter.assign_region(
+            || "assign foo",
+            |mut region| {
+                self.config.sel.enable(&mut region, 0)?;
+
+                let output = a
+                    .zip(b)
+                    .zip(c)
+                    .map(|((a, b), c)| if a == b { c } else { a - b });
+
+                let is_zero_chip = IsZero2Chip::new(self.config.is_zero_config.clone());
+                is_zero_chip.assign(
+                    &mut region,
+                    (a - b).map(|value| value.invert().unwrap_or(F::ZERO)),
+                )?;
+
+                region.assign_advice(|| "a", self.config.col, 0, || a)?;
+                region.assign_advice(|| "b", self.config.col, 1, || b)?;
+                region.assign_advice(|| "output", self.config.col, 2, || output)
+            },
+        )
+    }
+
+    pub fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        cell: AssignedCell<F, F>,
+    ) -> Result<(), plonk::Error> {
+        layouter.constrain_instance(cell.cell(), self.config.instance, 0)
+    }
+}
+
+pub struct FooCircuit<F, P = SimpleFloorPlanner> {
+    pub a: Value<F>,
+    pub b: Value<F>,
+    pub c: Value<F>,
+    _marker: PhantomData<P>,
+}
+
+impl<F> FooCircuit<F> {
+    pub fn new(a: Value<F>, b: Value<F>, c: Value<F>) -> Self {
+        Self::with_floor_planner(a, b, c)
+    }
+}
+
+impl<F, P> FooCircuit<F, P> {
+    pub fn with_floor_planner(a: Value<F>, b: Value<F>, c: Value<F>) -> Self {
+        Self {
+            a,
+            b,
+            c,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F: Field, P: FloorPlanner> Circuit<F> for FooCircuit<F, P> {
+    type Config = FooConfig<F>;
+    type FloorPlanner = P;
+
+    fn without_witnesses(&self) -> Self {
+        Self::with_floor_planner(Value::unknown(), Value::unknown(), Value::unknown())
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        FooChip::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let foo_chip = FooChip::new(config);
+
+        let cell = foo_chip.assign(layouter.namespace(|| "assign"), self.a, self.b, self.c)?;
+
+        foo_chip.expose_public(layouter.namespace(|| "expose public"), cell)
+    }
+}
@@ -1,7 +1,30 @@
+pub mod aggregation;
+pub mod builder;
+pub mod div_mod;
+pub mod ecc;
+#[cfg(feature = "evm")]
+pub mod evm;
+pub mod fibo;
+pub mod fixed_point;
+pub mod input;
+pub mod inverse;
 pub mod is_zero;
 pub mod is_zero2;
+pub mod memory;
+pub mod permutation;
+pub mod poseidon;
+pub mod prover;
 pub mod range_check;
+pub mod recurrence;
+pub mod schnorr;
+pub mod sha256;
+pub mod sort;
+pub mod sqrt;
+pub mod standard_plonk;
+pub mod sudoku;
+pub mod vm;
 
+use ff::PrimeField;
 use halo2_proofs::{arithmetic::Field, plonk::Circuit};
 
 pub fn plot_layout<P: AsRef<std::path::Path>, F: Field>(
@@ -12,10 +35,47 @@ pub fn plot_layout<P: AsRef<std::path::Path>, F: Field>(
 ) {
     use plotters::prelude::*;
     let root = BitMapBackend::new(&path, (1024, 3096)).into_drawing_area();
-    root.fill(&WHITE).unwrap();
-    let root = root.titled(title, ("sans-serif", 60)).unwrap();
+    draw_layout(&root, title, k, circuit);
+}
+
+// Like `plot_layout` with two circuits next to each other, e.g. to compare floor planners.
+pub fn plot_layouts<P: AsRef<std::path::Path>, F: Field>(
+    path: P,
+    left: (&str, &impl Circuit<F>),
+    right: (&str, &impl Circuit<F>),
+    k: u32,
+) {
+    use plotters::prelude::*;
+    let root = BitMapBackend::new(&path, (2048, 3096)).into_drawing_area();
+    let areas = root.split_evenly((1, 2));
+    draw_layout(&areas[0], left.0, k, left.1);
+    draw_layout(&areas[1], right.0, k, right.1);
+}
+
+fn draw_layout<DB: plotters::prelude::DrawingBackend, F: Field>(
+    area: &plotters::prelude::DrawingArea<DB, plotters::coord::Shift>,
+    title: &str,
+    k: u32,
+    circuit: &impl Circuit<F>,
+) {
+    use plotters::prelude::*;
+    area.fill(&WHITE).unwrap();
+    let area = area.titled(title, ("sans-serif", 60)).unwrap();
 
     halo2_proofs::dev::CircuitLayout::default()
-        .render(k, circuit, &root)
+        .render(k, circuit, &area)
         .unwrap();
 }
+
+// Only the low 128 bits are read, so the result is meaningless for larger values.
+// Both Pasta and BN254 fields use a little-endian repr.
+pub fn fe_to_u128<F: PrimeField>(v: &F) -> u128 {
+    let repr = v.to_repr();
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&repr.as_ref()[..16]);
+    u128::from_le_bytes(bytes)
+}
+
+pub fn fe_to_u64<F: PrimeField>(v: &F) -> u64 {
+    fe_to_u128(v) as u64
+}
@@ -0,0 +1,286 @@
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, Region, Value},
+    plonk::{self, Advice, Column, ConstraintSystem, Constraints, Expression, Selector},
+    poly::Rotation,
+};
+
+use crate::{
+    fe_to_u128,
+    permutation::{PermutationChip, PermutationConfig},
+    range_check::{
+        decompose::{DecomposeChip, DecomposeConfig},
+        example2::RangeCheckTable,
+    },
+};
+
+// Addresses are `u64`s, so the gaps between sorted addresses and timestamps fit in 8 byte limbs.
+const NUM_LIMBS: usize = 8;
+
+#[derive(Clone)]
+pub struct MemoryConfig<F> {
+    addr: Column<Advice>,
+    ts: Column<Advice>,
+    value: Column<Advice>,
+    is_write: Column<Advice>,
+    same_addr: Column<Advice>,
+    delta: Column<Advice>,
+    q_first: Selector,
+    q_step: Selector,
+    decompose_config: DecomposeConfig<F>,
+    permutation_config: PermutationConfig<4>,
+}
+
+// Read/write memory. Every access is recorded as `(addr, ts, value, is_write)` in execution order,
+// with `ts` a constant counter. `finalize` assigns the same accesses sorted by `(addr, ts)` and
+// constrains them to be a permutation of the recorded ones, where adjacent rows satisfy:
+//
+//  - same address: `ts_next - ts_cur - 1` is range checked and a read returns `value_cur`;
+//  - new address: `addr_next - addr_cur - 1` is range checked and a read returns zero.
+//
+// With fewer than `p / 2^64` accesses, the range checked gaps can't wrap around the modulus, so
+// every address appears in exactly one run of rows.
+pub struct MemoryChip<F> {
+    config: MemoryConfig<F>,
+    accesses: Vec<[AssignedCell<F, F>; 4]>,
+}
+
+impl<F: PrimeField> MemoryChip<F> {
+    pub fn new(config: MemoryConfig<F>) -> Self {
+        Self {
+            config,
+            accesses: vec![],
+        }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> MemoryConfig<F> {
+        let [addr, ts, value, is_write, same_addr, delta] = [(); 6].map(|_| meta.advice_column());
+        let constants = meta.fixed_column();
+        let q_first = meta.selector();
+        let q_step = meta.selector();
+
+        for column in [addr, ts, value, is_write, delta] {
+            meta.enable_equality(column);
+        }
+        meta.enable_constant(constants);
+
+        meta.create_gate("memory first access", |meta| {
+            let q_first = meta.query_selector(q_first);
+            let value = meta.query_advice(value, Rotation::cur());
+            let is_write = meta.query_advice(is_write, Rotation::cur());
+
+            Constraints::with_selector(q_first, [(Expression::Constant(F::ONE) - is_write) * value])
+        });
+
+        meta.create_gate("memory step", |meta| {
+            let q_step = meta.query_selector(q_step);
+            let one = Expression::Constant(F::ONE);
+
+            let addr_cur = meta.query_advice(addr, Rotation::cur());
+            let addr_next = meta.query_advice(addr, Rotation::next());
+            let ts_cur = meta.query_advice(ts, Rotation::cur());
+            let ts_next = meta.query_advice(ts, Rotation::next());
+            let value_cur = meta.query_advice(value, Rotation::cur());
+            let value_next = meta.query_advice(value, Rotation::next());
+            let is_read_next = one.clone() - meta.query_advice(is_write, Rotation::next());
+            let same_addr = meta.query_advice(same_addr, Rotation::cur());
+            let delta = meta.query_advice(delta, Rotation::cur());
+
+            let new_addr = one.clone() - same_addr.clone();
+            let addr_diff = addr_next - addr_cur;
+
+            Constraints::with_selector(
+                q_step,
+                [
+                    same_addr.clone() * new_addr.clone(),
+                    same_addr.clone() * addr_diff.clone(),
+                    delta
+                        - (same_addr.clone() * (ts_next - ts_cur - one.clone())
+                            + new_addr.clone() * (addr_diff - one)),
+                    is_read_next.clone() * same_addr * (value_next.clone() - value_cur),
+                    is_read_next * new_addr * value_next,
+                ],
+            )
+        });
+
+        let table = RangeCheckTable::new(meta);
+
+        MemoryConfig {
+            addr,
+            ts,
+            value,
+            is_write,
+            same_addr,
+            delta,
+            q_first,
+            q_step,
+            decompose_config: DecomposeChip::configure(meta, table),
+            permutation_config: PermutationChip::configure(meta),
+        }
+    }
+
+    pub fn load_table(&self, layouter: impl Layouter<F>) -> Result<(), plonk::Error> {
+        DecomposeChip::new(self.config.decompose_config.clone()).load_table(layouter)
+    }
+
+    pub fn write(
+        &mut self,
+        layouter: impl Layouter<F>,
+        addr: &AssignedCell<F, F>,
+        value: &AssignedCell<F, F>,
+    ) -> Result<(), plonk::Error> {
+        self.access(layouter, addr, true, |region, column| {
+            value.copy_advice(|| "value", region, column, 0)
+        })?;
+
+        Ok(())
+    }
+
+    // Reads of never written addresses return zero.
+    pub fn read(
+        &mut self,
+        layouter: impl Layouter<F>,
+        addr: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        let value = self.trace().zip(addr.value()).map(|(trace, addr)| {
+            trace
+                .iter()
+                .rev()
+                .find(|row| row[0] == *addr && row[3] == F::ONE)
+                .map_or(F::ZERO, |row| row[2])
+        });
+
+        self.access(layouter, addr, false, |region, column| {
+            region.assign_advice(|| "value", column, 0, || value)
+        })
+    }
+
+    fn access(
+        &mut self,
+        mut layouter: impl Layouter<F>,
+        addr: &AssignedCell<F, F>,
+        is_write: bool,
+        assign_value: impl Fn(
+            &mut Region<'_, F>,
+            Column<Advice>,
+        ) -> Result<AssignedCell<F, F>, plonk::Error>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        let ts = F::from(self.accesses.len() as u64);
+
+        let row = layouter.assign_region(
+            || "memory access",
+            |mut region| {
+                Ok([
+                    addr.copy_advice(|| "addr", &mut region, self.config.addr, 0)?,
+                    region.assign_advice_from_constant(|| "ts", self.config.ts, 0, ts)?,
+                    assign_value(&mut region, self.config.value)?,
+                    region.assign_advice_from_constant(
+                        || "is_write",
+                        self.config.is_write,
+                        0,
+                        F::from(is_write as u64),
+                    )?,
+                ])
+            },
+        )?;
+
+        let value = row[2].clone();
+        self.accesses.push(row);
+
+        Ok(value)
+    }
+
+    fn trace(&self) -> Value<Vec<[F; 4]>> {
+        self.accesses
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| cell.value().cloned())
+                    .collect::<Value<Vec<_>>>()
+                    .map(|row| row.try_into().unwrap())
+            })
+            .collect()
+    }
+
+    // Must be called once, after the last access.
+    pub fn finalize(&self, mut layouter: impl Layouter<F>) -> Result<(), plonk::Error> {
+        if self.accesses.is_empty() {
+            return Ok(());
+        }
+
+        let n = self.accesses.len();
+        let sorted = self.trace().map(|mut trace| {
+            trace.sort_by_key(|row| (fe_to_u128(&row[0]), fe_to_u128(&row[1])));
+            trace
+        });
+
+        let (rows, deltas) = layouter.assign_region(
+            || "sorted memory accesses",
+            |mut region| {
+                self.config.q_first.enable(&mut region, 0)?;
+
+                let columns = [
+                    self.config.addr,
+                    self.config.ts,
+                    self.config.value,
+                    self.config.is_write,
+                ];
+                let mut rows = Vec::with_capacity(n);
+                for i in 0..n {
+                    let mut row = Vec::with_capacity(4);
+                    for (j, column) in columns.iter().enumerate() {
+                        let value = sorted.as_ref().map(|sorted| sorted[i][j]);
+                        row.push(region.assign_advice(|| "sorted", *column, i, || value)?);
+                    }
+                    rows.push(row.try_into().unwrap());
+                }
+
+                let mut deltas = Vec::with_capacity(n - 1);
+                for i in 0..n - 1 {
+                    self.config.q_step.enable(&mut region, i)?;
+
+                    let pair = sorted.as_ref().map(|sorted| (sorted[i], sorted[i + 1]));
+                    let same_addr = pair.map(|(cur, next)| cur[0] == next[0]);
+                    let delta = pair.zip(same_addr).map(|((cur, next), same_addr)| {
+                        if same_addr {
+                            next[1] - cur[1] - F::ONE
+                        } else {
+                            next[0] - cur[0] - F::ONE
+                        }
+                    });
+
+                    region.assign_advice(
+                        || "same addr",
+                        self.config.same_addr,
+                        i,
+                        || same_addr.map(|same_addr| F::from(same_addr as u64)),
+                    )?;
+                    deltas.push(region.assign_advice(
+                        || "delta",
+                        self.config.delta,
+                        i,
+                        || delta,
+                    )?);
+                }
+
+                Ok((rows, deltas))
+            },
+        )?;
+
+        let decompose_chip = DecomposeChip::new(self.config.decompose_config.clone());
+        for (i, delta) in deltas.iter().enumerate() {
+            decompose_chip.range_check(
+                layouter.namespace(|| format!("range check delta {}", i)),
+                delta,
+                NUM_LIMBS,
+            )?;
+        }
+
+        let permutation_chip = PermutationChip::new(self.config.permutation_config.clone());
+        permutation_chip.assert_permutation_rows(
+            layouter.namespace(|| "sorted permutation"),
+            &self.accesses,
+            &rows,
+        )
+    }
+}
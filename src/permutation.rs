@@ -0,0 +1,159 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter, Value},
+    plonk::{
+        self, Advice, Challenge, Column, ConstraintSystem, Constraints, Expression, FirstPhase,
+        SecondPhase, Selector,
+    },
+    poly::Rotation,
+};
+
+#[derive(Clone)]
+pub struct PermutationConfig<const W: usize> {
+    a: [Column<Advice>; W],
+    b: [Column<Advice>; W],
+    z: Column<Advice>,
+    alpha: Challenge,
+    gamma: Challenge,
+    q_first: Selector,
+    q_step: Selector,
+    q_last: Selector,
+}
+
+// Multiset equality of rows of `W` cells. Each row is compressed to `sum(row[j] * alpha^j)`, and
+// the grand product of `(a_i + gamma) / (b_i + gamma)` is constrained to be one:
+//
+// a     | b     | z
+// a_0   | b_0   | 1
+// a_1   | b_1   | z_1 = (a_0 + gamma) / (b_0 + gamma)
+// ...   | ...   | ...
+//       |       | z_n = 1
+pub struct PermutationChip<F, const W: usize> {
+    config: PermutationConfig<W>,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field, const W: usize> PermutationChip<F, W> {
+    pub fn new(config: PermutationConfig<W>) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> PermutationConfig<W> {
+        let a = [(); W].map(|_| meta.advice_column_in(FirstPhase));
+        let b = [(); W].map(|_| meta.advice_column_in(FirstPhase));
+        let alpha = meta.challenge_usable_after(FirstPhase);
+        let gamma = meta.challenge_usable_after(FirstPhase);
+        let z = meta.advice_column_in(SecondPhase);
+        let q_first = meta.selector();
+        let q_step = meta.selector();
+        let q_last = meta.selector();
+
+        for column in a.iter().chain(b.iter()) {
+            meta.enable_equality(*column);
+        }
+
+        meta.create_gate("permutation boundary", |meta| {
+            let q_first = meta.query_selector(q_first);
+            let q_last = meta.query_selector(q_last);
+            let z = meta.query_advice(z, Rotation::cur());
+            let one = Expression::Constant(F::ONE);
+
+            vec![q_first * (z.clone() - one.clone()), q_last * (z - one)]
+        });
+
+        meta.create_gate("permutation step", |meta| {
+            let q_step = meta.query_selector(q_step);
+            let alpha = meta.query_challenge(alpha);
+            let gamma = meta.query_challenge(gamma);
+            let z_cur = meta.query_advice(z, Rotation::cur());
+            let z_next = meta.query_advice(z, Rotation::next());
+
+            let mut compress = |columns: &[Column<Advice>; W]| {
+                columns
+                    .iter()
+                    .map(|column| meta.query_advice(*column, Rotation::cur()))
+                    .fold(Expression::Constant(F::ZERO), |acc, v| {
+                        acc * alpha.clone() + v
+                    })
+            };
+            let a = compress(&a);
+            let b = compress(&b);
+
+            Constraints::with_selector(q_step, [z_next * (b + gamma.clone()) - z_cur * (a + gamma)])
+        });
+
+        PermutationConfig {
+            a,
+            b,
+            z,
+            alpha,
+            gamma,
+            q_first,
+            q_step,
+            q_last,
+        }
+    }
+
+    pub fn assert_permutation_rows(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &[[AssignedCell<F, F>; W]],
+        b: &[[AssignedCell<F, F>; W]],
+    ) -> Result<(), plonk::Error> {
+        if a.len() != b.len() {
+            return Err(plonk::Error::Synthesis);
+        }
+
+        let alpha = layouter.get_challenge(self.config.alpha);
+        let gamma = layouter.get_challenge(self.config.gamma);
+
+        layouter.assign_region(
+            || "permutation",
+            |mut region| {
+                let compress = |row: &[AssignedCell<F, F>; W]| {
+                    row.iter().fold(Value::known(F::ZERO), |acc, cell| {
+                        acc * alpha + cell.value()
+                    }) + gamma
+                };
+
+                self.config.q_first.enable(&mut region, 0)?;
+                let mut z = Value::known(F::ONE);
+                region.assign_advice(|| "z_0", self.config.z, 0, || z)?;
+
+                for (i, (row_a, row_b)) in a.iter().zip(b.iter()).enumerate() {
+                    self.config.q_step.enable(&mut region, i)?;
+
+                    for (j, (cell_a, cell_b)) in row_a.iter().zip(row_b.iter()).enumerate() {
+                        cell_a.copy_advice(|| "a", &mut region, self.config.a[j], i)?;
+                        cell_b.copy_advice(|| "b", &mut region, self.config.b[j], i)?;
+                    }
+
+                    // A zero denominator only happens with negligible probability.
+                    let b_inv = compress(row_b).map(|v| v.invert().unwrap_or(F::ZERO));
+                    z = z * compress(row_a) * b_inv;
+                    region.assign_advice(|| format!("z_{}", i + 1), self.config.z, i + 1, || z)?;
+                }
+
+                self.config.q_last.enable(&mut region, a.len())
+            },
+        )
+    }
+}
+
+impl<F: Field> PermutationChip<F, 1> {
+    pub fn assert_permutation(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &[AssignedCell<F, F>],
+        b: &[AssignedCell<F, F>],
+    ) -> Result<(), plonk::Error> {
+        let a: Vec<_> = a.iter().map(|cell| [cell.clone()]).collect();
+        let b: Vec<_> = b.iter().map(|cell| [cell.clone()]).collect();
+        self.assert_permutation_rows(layouter, &a, &b)
+    }
+}
@@ -0,0 +1,59 @@
+use halo2_gadgets::poseidon::{
+    primitives::{self as poseidon, ConstantLength, P128Pow5T3},
+    Hash, Pow5Chip, Pow5Config,
+};
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter},
+    halo2curves::pasta::Fp,
+    plonk::{self, ConstraintSystem},
+};
+
+const WIDTH: usize = 3;
+const RATE: usize = 2;
+
+pub fn poseidon_hash<const L: usize>(message: [Fp; L]) -> Fp {
+    poseidon::Hash::<_, P128Pow5T3, ConstantLength<L>, WIDTH, RATE>::init().hash(message)
+}
+
+#[derive(Clone, Debug)]
+pub struct PoseidonConfig {
+    pow5_config: Pow5Config<Fp, WIDTH, RATE>,
+}
+
+// Hashes a fixed number of Pasta `Fp` elements with Poseidon (P128Pow5T3) from `halo2_gadgets`.
+pub struct PoseidonChip<const L: usize> {
+    config: PoseidonConfig,
+}
+
+impl<const L: usize> PoseidonChip<L> {
+    pub fn new(config: PoseidonConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<Fp>) -> PoseidonConfig {
+        let state = [(); WIDTH].map(|_| meta.advice_column());
+        let partial_sbox = meta.advice_column();
+        let rc_a = [(); WIDTH].map(|_| meta.fixed_column());
+        let rc_b = [(); WIDTH].map(|_| meta.fixed_column());
+
+        meta.enable_constant(rc_b[0]);
+
+        let pow5_config = Pow5Chip::configure::<P128Pow5T3>(meta, state, partial_sbox, rc_a, rc_b);
+
+        PoseidonConfig { pow5_config }
+    }
+
+    pub fn hash(
+        &self,
+        mut layouter: impl Layouter<Fp>,
+        message: [AssignedCell<Fp, Fp>; L],
+    ) -> Result<AssignedCell<Fp, Fp>, plonk::Error> {
+        let chip = Pow5Chip::construct(self.config.pow5_config.clone());
+
+        let hasher = Hash::<_, _, P128Pow5T3, ConstantLength<L>, WIDTH, RATE>::init(
+            chip,
+            layouter.namespace(|| "init"),
+        )?;
+        hasher.hash(layouter.namespace(|| "hash"), message)
+    }
+}
@@ -0,0 +1,164 @@
+use halo2_proofs::{
+    halo2curves::pasta::{EqAffine, Fp},
+    plonk::{
+        self, create_proof, keygen_pk, keygen_vk, verify_proof, Circuit, ProvingKey, VerifyingKey,
+    },
+    poly::{
+        commitment::ParamsProver,
+        ipa::{
+            commitment::{IPACommitmentScheme, ParamsIPA},
+            multiopen::{ProverIPA, VerifierIPA},
+            strategy::{AccumulatorStrategy, SingleStrategy},
+        },
+        VerificationStrategy,
+    },
+    transcript::{
+        Blake2bRead, Blake2bWrite, Challenge255, TranscriptReadBuffer, TranscriptWriterBuffer,
+    },
+};
+use rand_chacha::ChaCha20Rng;
+use rand_core::{RngCore, SeedableRng};
+
+// Real (non-mock) proving with IPA commitments over the Pasta cycle: circuits are over `Fp`, and
+// commitments are Vesta points (`EqAffine`).
+pub type Params = ParamsIPA<EqAffine>;
+
+pub fn setup(k: u32) -> Params {
+    ParamsIPA::new(k)
+}
+
+pub fn keygen<C: Circuit<Fp>>(
+    params: &Params,
+    circuit: &C,
+) -> Result<ProvingKey<EqAffine>, plonk::Error> {
+    let vk = keygen_vk(params, circuit)?;
+    keygen_pk(params, vk, circuit)
+}
+
+// `instances` holds the values of each instance column.
+pub fn prove<C: Circuit<Fp>>(
+    params: &Params,
+    pk: &ProvingKey<EqAffine>,
+    circuit: C,
+    instances: &[&[Fp]],
+    rng: impl RngCore,
+) -> Result<Vec<u8>, plonk::Error> {
+    let mut transcript = Blake2bWrite::<_, EqAffine, Challenge255<_>>::init(vec![]);
+    create_proof::<IPACommitmentScheme<EqAffine>, ProverIPA<'_, EqAffine>, _, _, _, _>(
+        params,
+        pk,
+        &[circuit],
+        &[instances],
+        rng,
+        &mut transcript,
+    )?;
+
+    Ok(transcript.finalize())
+}
+
+// Params, keys and the transcript are already deterministic, so with the blinding drawn from a
+// seeded RNG the proof only depends on the circuit, its witnesses and `seed`.
+pub fn prove_seeded<C: Circuit<Fp>>(
+    params: &Params,
+    pk: &ProvingKey<EqAffine>,
+    circuit: C,
+    instances: &[&[Fp]],
+    seed: u64,
+) -> Result<Vec<u8>, plonk::Error> {
+    prove(
+        params,
+        pk,
+        circuit,
+        instances,
+        ChaCha20Rng::seed_from_u64(seed),
+    )
+}
+
+pub fn verify(
+    params: &Params,
+    vk: &VerifyingKey<EqAffine>,
+    proof: &[u8],
+    instances: &[&[Fp]],
+) -> Result<(), plonk::Error> {
+    let strategy = SingleStrategy::new(params);
+    let mut transcript = Blake2bRead::<_, EqAffine, Challenge255<_>>::init(proof);
+    verify_proof::<IPACommitmentScheme<EqAffine>, VerifierIPA<'_, EqAffine>, _, _, _>(
+        params,
+        vk,
+        strategy,
+        &[instances],
+        &mut transcript,
+    )
+}
+
+// A proof with the values of each instance column.
+pub type BatchItem = (Vec<u8>, Vec<Vec<Fp>>);
+
+// Verifies proofs of the same circuit with one accumulated MSM check. When that fails, the batch
+// is bisected until the invalid proofs are found, and their indices are returned.
+pub fn batch_verify(
+    params: &Params,
+    vk: &VerifyingKey<EqAffine>,
+    items: &[BatchItem],
+) -> Result<(), Vec<usize>> {
+    let mut invalid = vec![];
+    bisect(params, vk, items, 0, &mut invalid);
+
+    if invalid.is_empty() {
+        Ok(())
+    } else {
+        Err(invalid)
+    }
+}
+
+fn bisect(
+    params: &Params,
+    vk: &VerifyingKey<EqAffine>,
+    items: &[BatchItem],
+    offset: usize,
+    invalid: &mut Vec<usize>,
+) {
+    let proofs = items.iter().map(|(proof, instances)| {
+        let instances: Vec<_> = instances.iter().map(|column| column.as_slice()).collect();
+        (proof.as_slice(), instances)
+    });
+    if items.is_empty() || accumulate(params, vk, proofs).is_ok() {
+        return;
+    }
+
+    if items.len() == 1 {
+        invalid.push(offset);
+        return;
+    }
+
+    let (left, right) = items.split_at(items.len() / 2);
+    bisect(params, vk, left, offset, invalid);
+    bisect(params, vk, right, offset + left.len(), invalid);
+}
+
+// Each proof is checked up to its IPA opening, whose MSM is accumulated rather than evaluated.
+// The accumulator, a random linear combination of all the openings, is then checked with one MSM
+// over the commitment key.
+fn accumulate<'a>(
+    params: &Params,
+    vk: &VerifyingKey<EqAffine>,
+    proofs: impl IntoIterator<Item = (&'a [u8], Vec<&'a [Fp]>)>,
+) -> Result<(), plonk::Error> {
+    let mut strategy = AccumulatorStrategy::new(params);
+    for (proof, instances) in proofs {
+        let mut transcript = Blake2bRead::<_, EqAffine, Challenge255<_>>::init(proof);
+        strategy = verify_proof::<IPACommitmentScheme<EqAffine>, VerifierIPA<'_, EqAffine>, _, _, _>(
+            params,
+            vk,
+            strategy,
+            &[&instances],
+            &mut transcript,
+        )?;
+    }
+
+    if strategy.finalize() {
+        Ok(())
+    } else {
+        Err(plonk::Error::ConstraintSystemFailure)
+    }
+}
@@ -0,0 +1,126 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter, Value},
+    plonk::{self, Advice, Column, ConstraintSystem, Constraints, Expression, Selector},
+    poly::Rotation,
+};
+
+use ff::PrimeField;
+
+use super::example2::RangeCheckTable;
+
+pub const LIMB_BITS: usize = 8;
+pub const LIMB_RANGE: usize = 1 << LIMB_BITS;
+
+// Checks `value < 2^(LIMB_BITS * num_limbs)` with a running sum over byte limbs:
+//
+//  z_0 = value, z_{i+1} = (z_i - limb_i) / 2^LIMB_BITS, z_{num_limbs} = 0
+//
+// and every `limb_i = z_i - 2^LIMB_BITS * z_{i+1}` is looked up in a `RangeCheckTable`.
+#[derive(Clone)]
+pub struct DecomposeConfig<F> {
+    q_lookup: Selector,
+    q_end: Selector,
+    z: Column<Advice>,
+    table: RangeCheckTable<F, LIMB_RANGE>,
+}
+
+pub struct DecomposeChip<F> {
+    config: DecomposeConfig<F>,
+}
+
+impl<F: PrimeField> DecomposeChip<F> {
+    pub fn new(config: DecomposeConfig<F>) -> Self {
+        Self { config }
+    }
+
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        table: RangeCheckTable<F, LIMB_RANGE>,
+    ) -> DecomposeConfig<F> {
+        let q_lookup = meta.complex_selector();
+        let q_end = meta.selector();
+        let z = meta.advice_column();
+
+        meta.enable_equality(z);
+
+        meta.lookup("decompose limb", |meta| {
+            let q_lookup = meta.query_selector(q_lookup);
+            let z_cur = meta.query_advice(z, Rotation::cur());
+            let z_next = meta.query_advice(z, Rotation::next());
+
+            let limb = z_cur - z_next * Expression::Constant(F::from(LIMB_RANGE as u64));
+
+            vec![(q_lookup * limb, *table.table_column())]
+        });
+
+        meta.create_gate("decompose end", |meta| {
+            let q_end = meta.query_selector(q_end);
+            let z = meta.query_advice(z, Rotation::cur());
+
+            Constraints::with_selector(q_end, [z])
+        });
+
+        DecomposeConfig {
+            q_lookup,
+            q_end,
+            z,
+            table,
+        }
+    }
+
+    pub fn load_table(&self, layouter: impl Layouter<F>) -> Result<(), plonk::Error> {
+        self.config.table.load(layouter)
+    }
+
+    pub fn range_check(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: &AssignedCell<F, F>,
+        num_limbs: usize,
+    ) -> Result<(), plonk::Error> {
+        let shift_inv = F::from(LIMB_RANGE as u64).invert().unwrap();
+
+        layouter.assign_region(
+            || "decompose",
+            |mut region| {
+                let mut z = value.copy_advice(|| "z_0", &mut region, self.config.z, 0)?;
+
+                for i in 0..num_limbs {
+                    self.config.q_lookup.enable(&mut region, i)?;
+
+                    let z_next = z.value().map(|z| {
+                        let limb = F::from(z.to_repr().as_ref()[0] as u64);
+                        (*z - limb) * shift_inv
+                    });
+                    z = region.assign_advice(
+                        || format!("z_{}", i + 1),
+                        self.config.z,
+                        i + 1,
+                        || z_next,
+                    )?;
+                }
+
+                self.config.q_end.enable(&mut region, num_limbs)?;
+
+                Ok(())
+            },
+        )
+    }
+
+    pub fn assign_and_range_check(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: Value<F>,
+        num_limbs: usize,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        let cell = layouter.assign_region(
+            || "assign value",
+            |mut region| region.assign_advice(|| "value", self.config.z, 0, || value),
+        )?;
+
+        self.range_check(layouter.namespace(|| "range check"), &cell, num_limbs)?;
+
+        Ok(cell)
+    }
+}
@@ -2,8 +2,11 @@ use std::marker::PhantomData;
 
 use halo2_proofs::{
     arithmetic::Field,
-    circuit::{Layouter, Value},
-    plonk::{self, Advice, Column, ConstraintSystem, Constraints, Expression, Selector},
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    plonk::{
+        self, Advice, Circuit, Column, ConstraintSystem, Constraints, Expression, FloorPlanner,
+        Selector,
+    },
     poly::Rotation,
 };
 
@@ -50,17 +53,59 @@ impl<F: Field, const RANGE: usize> RangeCheckChip<F, RANGE> {
         config
     }
 
-    pub fn assign(&self, mut layouter: impl Layouter<F>, v: Option<F>) -> Result<(), plonk::Error> {
+    pub fn assign(&self, mut layouter: impl Layouter<F>, v: Value<F>) -> Result<(), plonk::Error> {
         layouter.assign_region(
             || "assign value",
             |mut region| {
                 self.config.sel.enable(&mut region, 0)?;
 
-                let v = v.ok_or(plonk::Error::Synthesis)?;
-                region.assign_advice(|| "value", self.config.value, 0, || Value::known(v))?;
+                region.assign_advice(|| "value", self.config.value, 0, || v)?;
 
                 Ok(())
             },
         )
     }
 }
+
+pub struct RangeCheckCircuit<F, const RANGE: usize, P = SimpleFloorPlanner> {
+    pub value: Value<F>,
+    _marker: PhantomData<P>,
+}
+
+impl<F, const RANGE: usize> RangeCheckCircuit<F, RANGE> {
+    pub fn new(value: Value<F>) -> Self {
+        Self::with_floor_planner(value)
+    }
+}
+
+impl<F, const RANGE: usize, P> RangeCheckCircuit<F, RANGE, P> {
+    pub fn with_floor_planner(value: Value<F>) -> Self {
+        Self {
+            value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F: Field, const RANGE: usize, P: FloorPlanner> Circuit<F> for RangeCheckCircuit<F, RANGE, P> {
+    type Config = RangeCheckConfig;
+    type FloorPlanner = P;
+
+    fn without_witnesses(&self) -> Self {
+        Self::with_floor_planner(Value::unknown())
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        RangeCheckChip::<F, RANGE>::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let range_check_chip = RangeCheckChip::<F, RANGE>::new(config);
+
+        range_check_chip.assign(layouter.namespace(|| "assign"), self.value)
+    }
+}
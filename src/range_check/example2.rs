@@ -1,14 +1,23 @@
+use std::marker::PhantomData;
+
 use halo2_proofs::{
     arithmetic::Field,
-    circuit::{Layouter, Value},
-    plonk::{self, Advice, Column, ConstraintSystem, Constraints, Expression, Selector},
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    plonk::{
+        self, Advice, Circuit, Column, ConstraintSystem, Constraints, Expression, FloorPlanner,
+        Selector,
+    },
     poly::Rotation,
 };
 
-use self::table::RangeCheckTable;
+pub use self::table::RangeCheckTable;
 
 mod table;
 
+// Above this range the lookup is enabled instead of the expression. `range_check_report`
+// measures both strategies to compare against it.
+pub const LOOKUP_THRESHOLD: usize = 256;
+
 #[derive(Clone)]
 pub struct RangeCheckConfig<F, const RANGE: usize> {
     q_check: Selector,
@@ -40,7 +49,7 @@ impl<F: Field, const RANGE: usize> RangeCheckChip<F, RANGE> {
             let value = meta.query_advice(value_col, Rotation::cur());
 
             let (range_check, _) =
-                (0..RANGE)
+                (0..(RANGE - 1))
                     .into_iter()
                     .fold((value.clone(), F::ZERO), |(acc, v), _| {
                         let v = v + F::ONE;
@@ -72,26 +81,65 @@ impl<F: Field, const RANGE: usize> RangeCheckChip<F, RANGE> {
     pub fn assign(
         &self,
         mut layouter: impl Layouter<F>,
-        value: Option<F>,
+        value: Value<F>,
     ) -> Result<(), plonk::Error> {
         layouter.assign_region(
             || "assign region",
             |mut region| {
-                if RANGE <= 256 {
+                if RANGE <= LOOKUP_THRESHOLD {
                     self.config.q_check.enable(&mut region, 0)?;
                 } else {
                     self.config.q_table.enable(&mut region, 0)?;
                 }
 
-                let value = value.ok_or(plonk::Error::Synthesis)?;
-                region.assign_advice(
-                    || "assign",
-                    self.config.value_col,
-                    0,
-                    || Value::known(value),
-                )?;
+                region.assign_advice(|| "assign", self.config.value_col, 0, || value)?;
                 Ok(())
             },
         )
     }
 }
+
+pub struct RangeCheckCircuit<F, const RANGE: usize, P = SimpleFloorPlanner> {
+    pub value: Value<F>,
+    _marker: PhantomData<P>,
+}
+
+impl<F, const RANGE: usize> RangeCheckCircuit<F, RANGE> {
+    pub fn new(value: Value<F>) -> Self {
+        Self::with_floor_planner(value)
+    }
+}
+
+impl<F, const RANGE: usize, P> RangeCheckCircuit<F, RANGE, P> {
+    pub fn with_floor_planner(value: Value<F>) -> Self {
+        Self {
+            value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F: Field, const RANGE: usize, P: FloorPlanner> Circuit<F> for RangeCheckCircuit<F, RANGE, P> {
+    type Config = RangeCheckConfig<F, RANGE>;
+    type FloorPlanner = P;
+
+    fn without_witnesses(&self) -> Self {
+        Self::with_floor_planner(Value::unknown())
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        RangeCheckChip::<F, RANGE>::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        let range_check_chip = RangeCheckChip::<F, RANGE>::new(config);
+
+        range_check_chip.load_table(layouter.namespace(|| "load"))?;
+
+        range_check_chip.assign(layouter.namespace(|| "assign"), self.value)
+    }
+}
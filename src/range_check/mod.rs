@@ -1,2 +1,3 @@
+pub mod decompose;
 pub mod example1;
 pub mod example2;
@@ -0,0 +1,248 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter, Region, Value},
+    plonk::{self, Advice, Column, ConstraintSystem, Constraints, Expression, Fixed, Selector},
+    poly::Rotation,
+};
+
+// `x_n = c_1 * x_{n-1} + ... + c_K * x_{n-K}`, with `coeffs = [c_1, ..., c_K]` and
+// `init = [x_0, ..., x_{K-1}]`. Returns `x_0, ..., x_n`.
+pub fn linear_recurrence<F: Field, const K: usize>(
+    coeffs: &[F; K],
+    init: &[F; K],
+    n: usize,
+) -> Vec<F> {
+    let mut xs = init.to_vec();
+    while xs.len() <= n {
+        let next = coeffs
+            .iter()
+            .zip(xs.iter().rev())
+            .fold(F::ZERO, |acc, (c, x)| acc + *c * x);
+        xs.push(next);
+    }
+    xs.truncate(n + 1);
+    xs
+}
+
+// `x_K - (c_1 * x_{K-1} + ... + c_K * x_0)` over `xs = [x_0, ..., x_K]`. The coefficients live in
+// fixed columns, so one configuration proves any recurrence of order `K`.
+fn recurrence_constraint<F: Field, const K: usize>(
+    coeffs: [Expression<F>; K],
+    xs: &[Expression<F>],
+) -> Expression<F> {
+    let sum = coeffs
+        .into_iter()
+        .zip(xs[..K].iter().rev())
+        .fold(Expression::Constant(F::ZERO), |acc, (c, x)| {
+            acc + c * x.clone()
+        });
+
+    xs[K].clone() - sum
+}
+
+fn assign_coeffs<F: Field, const K: usize>(
+    region: &mut Region<'_, F>,
+    columns: &[Column<Fixed>; K],
+    coeffs: &[F; K],
+    offset: usize,
+) -> Result<(), plonk::Error> {
+    for (column, coeff) in columns.iter().zip(coeffs.iter()) {
+        region.assign_fixed(|| "coeff", *column, offset, || Value::known(*coeff))?;
+    }
+    Ok(())
+}
+
+fn next_value<F: Field, const K: usize>(coeffs: &[F; K], prev: &[AssignedCell<F, F>]) -> Value<F> {
+    coeffs
+        .iter()
+        .zip(prev.iter().rev())
+        .fold(Value::known(F::ZERO), |acc, (c, x)| {
+            acc + x.value().map(|x| *c * x)
+        })
+}
+
+#[derive(Clone)]
+pub struct MultiColumnRecurrenceConfig<const K: usize> {
+    // `K + 1` columns
+    xs: Vec<Column<Advice>>,
+    coeffs: [Column<Fixed>; K],
+    sel: Selector,
+}
+
+// Like fib1, one row per step with the last `K` terms copied from the previous row:
+//
+// x_0 | x_1 | ... | x_K
+// x_1 | x_2 | ... | x_{K+1}
+// ...
+pub struct MultiColumnRecurrenceChip<F, const K: usize> {
+    config: MultiColumnRecurrenceConfig<K>,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field, const K: usize> MultiColumnRecurrenceChip<F, K> {
+    pub fn new(config: MultiColumnRecurrenceConfig<K>) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> MultiColumnRecurrenceConfig<K> {
+        let xs: Vec<_> = (0..=K).map(|_| meta.advice_column()).collect();
+        let coeffs = [(); K].map(|_| meta.fixed_column());
+        let sel = meta.selector();
+
+        for column in xs.iter() {
+            meta.enable_equality(*column);
+        }
+
+        meta.create_gate("linear recurrence", |meta| {
+            let sel = meta.query_selector(sel);
+            let xs: Vec<_> = xs
+                .iter()
+                .map(|column| meta.query_advice(*column, Rotation::cur()))
+                .collect();
+            let coeffs = coeffs.map(|column| meta.query_fixed(column, Rotation::cur()));
+
+            Constraints::with_selector(sel, [recurrence_constraint(coeffs, &xs)])
+        });
+
+        MultiColumnRecurrenceConfig { xs, coeffs, sel }
+    }
+
+    // Returns the cell holding `x_n`, for `n >= K`.
+    pub fn assign(
+        &self,
+        mut layouter: impl Layouter<F>,
+        coeffs: &[F; K],
+        init: &[Value<F>; K],
+        n: usize,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        assert!(n >= K);
+
+        layouter.assign_region(
+            || "linear recurrence",
+            |mut region| {
+                let mut prev = init
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| {
+                        region.assign_advice(|| format!("x_{}", i), self.config.xs[i], 0, || *x)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                for row in 0..=n - K {
+                    self.config.sel.enable(&mut region, row)?;
+                    assign_coeffs(&mut region, &self.config.coeffs, coeffs, row)?;
+
+                    if row > 0 {
+                        for (i, cell) in prev.iter().enumerate() {
+                            cell.copy_advice(|| "copy", &mut region, self.config.xs[i], row)?;
+                        }
+                    }
+
+                    let next = region.assign_advice(
+                        || format!("x_{}", row + K),
+                        self.config.xs[K],
+                        row,
+                        || next_value(coeffs, &prev),
+                    )?;
+
+                    prev.remove(0);
+                    prev.push(next);
+                }
+
+                Ok(prev.pop().unwrap())
+            },
+        )
+    }
+}
+
+#[derive(Clone)]
+pub struct SingleColumnRecurrenceConfig<const K: usize> {
+    x: Column<Advice>,
+    coeffs: [Column<Fixed>; K],
+    sel: Selector,
+}
+
+// Like fib3, all terms in one column with the gate reaching `K` rows ahead:
+//
+// x   | sel | c_1 .. c_K
+// x_0 |  1  |
+// x_1 |  1  |
+// ... |     |
+// x_n |     |
+pub struct SingleColumnRecurrenceChip<F, const K: usize> {
+    config: SingleColumnRecurrenceConfig<K>,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field, const K: usize> SingleColumnRecurrenceChip<F, K> {
+    pub fn new(config: SingleColumnRecurrenceConfig<K>) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> SingleColumnRecurrenceConfig<K> {
+        let x = meta.advice_column();
+        let coeffs = [(); K].map(|_| meta.fixed_column());
+        let sel = meta.selector();
+
+        meta.enable_equality(x);
+
+        meta.create_gate("linear recurrence", |meta| {
+            let sel = meta.query_selector(sel);
+            let xs: Vec<_> = (0..=K)
+                .map(|i| meta.query_advice(x, Rotation(i as i32)))
+                .collect();
+            let coeffs = coeffs.map(|column| meta.query_fixed(column, Rotation::cur()));
+
+            Constraints::with_selector(sel, [recurrence_constraint(coeffs, &xs)])
+        });
+
+        SingleColumnRecurrenceConfig { x, coeffs, sel }
+    }
+
+    // Returns the cell holding `x_n`, for `n >= K`.
+    pub fn assign(
+        &self,
+        mut layouter: impl Layouter<F>,
+        coeffs: &[F; K],
+        init: &[Value<F>; K],
+        n: usize,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        assert!(n >= K);
+
+        layouter.assign_region(
+            || "linear recurrence",
+            |mut region| {
+                let mut xs = init
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| {
+                        region.assign_advice(|| format!("x_{}", i), self.config.x, i, || *x)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                for row in 0..=n - K {
+                    self.config.sel.enable(&mut region, row)?;
+                    assign_coeffs(&mut region, &self.config.coeffs, coeffs, row)?;
+
+                    let next = next_value(coeffs, &xs[row..]);
+                    xs.push(region.assign_advice(
+                        || format!("x_{}", row + K),
+                        self.config.x,
+                        row + K,
+                        || next,
+                    )?);
+                }
+
+                Ok(xs.pop().unwrap())
+            },
+        )
+    }
+}
@@ -0,0 +1,159 @@
+use ff::{Field, PrimeField};
+use group::{prime::PrimeCurveAffine, Curve};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    halo2curves::pasta::{pallas, Fp},
+    plonk::{self, Advice, Circuit, Column, ConstraintSystem, Instance},
+};
+use rand_core::RngCore;
+
+use crate::{
+    ecc::{coords, EccChip, EccConfig},
+    poseidon::{poseidon_hash, PoseidonChip, PoseidonConfig},
+};
+
+// `e = H(R, PK, m)`, as a base field element.
+pub fn challenge(r: pallas::Affine, pk: pallas::Affine, msg: Fp) -> Fp {
+    let (r_x, r_y) = coords(r);
+    let (pk_x, pk_y) = coords(pk);
+    poseidon_hash([r_x, r_y, pk_x, pk_y, msg])
+}
+
+// Since `p < q`, every base field element is also a valid scalar.
+fn to_scalar(e: Fp) -> pallas::Scalar {
+    pallas::Scalar::from_repr(e.to_repr()).unwrap()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub r: pallas::Affine,
+    pub s: pallas::Scalar,
+}
+
+pub struct SigningKey {
+    sk: pallas::Scalar,
+}
+
+impl SigningKey {
+    pub fn random(rng: impl RngCore) -> Self {
+        Self {
+            sk: pallas::Scalar::random(rng),
+        }
+    }
+
+    pub fn public_key(&self) -> pallas::Affine {
+        (pallas::Affine::generator() * self.sk).to_affine()
+    }
+
+    // `s = k + e * sk` with `R = k * G`.
+    pub fn sign(&self, msg: Fp, rng: impl RngCore) -> Signature {
+        let k = pallas::Scalar::random(rng);
+        let r = (pallas::Affine::generator() * k).to_affine();
+        let e = to_scalar(challenge(r, self.public_key(), msg));
+
+        Signature {
+            r,
+            s: k + e * self.sk,
+        }
+    }
+}
+
+// `s * G == R + e * PK`
+pub fn verify(pk: pallas::Affine, msg: Fp, sig: &Signature) -> bool {
+    let e = to_scalar(challenge(sig.r, pk, msg));
+    pallas::Affine::generator() * sig.s == sig.r + pk * e
+}
+
+#[derive(Clone)]
+pub struct SchnorrConfig {
+    ecc_config: EccConfig,
+    poseidon_config: PoseidonConfig,
+    advice: Column<Advice>,
+    instance: Column<Instance>,
+}
+
+// The public key and the message are the public instances `[pk.x, pk.y, msg]`.
+#[derive(Default)]
+pub struct SchnorrCircuit {
+    pub pk: Value<pallas::Affine>,
+    pub msg: Value<Fp>,
+    pub sig: Value<Signature>,
+}
+
+impl SchnorrCircuit {
+    pub fn instances(pk: pallas::Affine, msg: Fp) -> Vec<Fp> {
+        let (pk_x, pk_y) = coords(pk);
+        vec![pk_x, pk_y, msg]
+    }
+}
+
+impl Circuit<Fp> for SchnorrCircuit {
+    type Config = SchnorrConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let ecc_config = EccChip::<pallas::Affine>::configure(meta);
+        let poseidon_config = PoseidonChip::<5>::configure(meta);
+
+        let advice = meta.advice_column();
+        let instance = meta.instance_column();
+        meta.enable_equality(advice);
+        meta.enable_equality(instance);
+
+        SchnorrConfig {
+            ecc_config,
+            poseidon_config,
+            advice,
+            instance,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), plonk::Error> {
+        let ecc_chip = EccChip::<pallas::Affine>::new(config.ecc_config);
+        let poseidon_chip = PoseidonChip::<5>::new(config.poseidon_config);
+
+        let pk = ecc_chip.witness_point(layouter.namespace(|| "pk"), self.pk)?;
+        let r = ecc_chip.witness_point(layouter.namespace(|| "r"), self.sig.map(|sig| sig.r))?;
+        let s =
+            ecc_chip.witness_scalar_bits(layouter.namespace(|| "s"), self.sig.map(|sig| sig.s))?;
+
+        let msg = layouter.assign_region(
+            || "msg",
+            |mut region| region.assign_advice(|| "msg", config.advice, 0, || self.msg),
+        )?;
+
+        layouter.constrain_instance(pk.x.cell(), config.instance, 0)?;
+        layouter.constrain_instance(pk.y.cell(), config.instance, 1)?;
+        layouter.constrain_instance(msg.cell(), config.instance, 2)?;
+
+        let e = poseidon_chip.hash(
+            layouter.namespace(|| "challenge"),
+            [r.x.clone(), r.y.clone(), pk.x.clone(), pk.y.clone(), msg],
+        )?;
+        let e = ecc_chip.decompose(
+            layouter.namespace(|| "decompose e"),
+            &e,
+            Fp::NUM_BITS as usize,
+        )?;
+        // Otherwise the bits of `e + p` would also pass, giving a different `e * pk`.
+        ecc_chip.assert_canonical(layouter.namespace(|| "canonical e"), &e)?;
+
+        let lhs = ecc_chip.mul_fixed(
+            layouter.namespace(|| "s * G"),
+            &s,
+            pallas::Affine::generator(),
+        )?;
+        let e_pk = ecc_chip.mul(layouter.namespace(|| "e * pk"), &e, &pk)?;
+        let rhs = ecc_chip.add(layouter.namespace(|| "r + e * pk"), &r, &e_pk)?;
+
+        ecc_chip.constrain_equal(layouter.namespace(|| "s * G == r + e * pk"), &lhs, &rhs)
+    }
+}
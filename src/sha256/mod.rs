@@ -0,0 +1,706 @@
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, Value},
+    plonk::{self, Advice, Column, ConstraintSystem, Fixed, Selector},
+    poly::Rotation,
+};
+
+use crate::fe_to_u128;
+
+pub use self::table::SpreadTable;
+use self::table::{even_bits, spread, MAX_CHUNK_BITS};
+
+mod table;
+
+pub const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+pub const BLOCK_BYTES: usize = 64;
+
+#[derive(Clone, Copy)]
+enum Shift {
+    Rotr(usize),
+    Shr(usize),
+}
+
+impl Shift {
+    fn amount(&self) -> usize {
+        match self {
+            Shift::Rotr(r) | Shift::Shr(r) => *r,
+        }
+    }
+
+    // Where the bit at `offset` ends up, if anywhere.
+    fn position(&self, offset: usize) -> Option<usize> {
+        match *self {
+            Shift::Rotr(r) => Some((offset + 32 - r) % 32),
+            Shift::Shr(r) => offset.checked_sub(r),
+        }
+    }
+
+    fn apply(&self, v: u32) -> u32 {
+        match *self {
+            Shift::Rotr(r) => v.rotate_right(r as u32),
+            Shift::Shr(r) => v >> r,
+        }
+    }
+}
+
+const BIG_SIGMA_0: [Shift; 3] = [Shift::Rotr(2), Shift::Rotr(13), Shift::Rotr(22)];
+const BIG_SIGMA_1: [Shift; 3] = [Shift::Rotr(6), Shift::Rotr(11), Shift::Rotr(25)];
+const SMALL_SIGMA_0: [Shift; 3] = [Shift::Rotr(7), Shift::Rotr(18), Shift::Shr(3)];
+const SMALL_SIGMA_1: [Shift; 3] = [Shift::Rotr(17), Shift::Rotr(19), Shift::Shr(10)];
+
+const BYTE_BOUNDARIES: [usize; 5] = [0, 8, 16, 24, 32];
+
+// Appends `0x80`, zeros and the 64-bit big-endian bit length, up to a multiple of `BLOCK_BYTES`.
+pub fn pad(message: &[u8]) -> Vec<u8> {
+    let mut padded = message.to_vec();
+    padded.push(0x80);
+    while padded.len() % BLOCK_BYTES != BLOCK_BYTES - 8 {
+        padded.push(0);
+    }
+    padded.extend((message.len() as u64 * 8).to_be_bytes());
+    padded
+}
+
+fn shifted_xor(v: u32, shifts: [Shift; 3]) -> u32 {
+    shifts.iter().fold(0, |acc, shift| acc ^ shift.apply(v))
+}
+
+pub fn sha256(message: &[u8]) -> [u32; 8] {
+    let mut state = IV;
+
+    for block in pad(message).chunks(BLOCK_BYTES) {
+        let mut w: Vec<u32> = block
+            .chunks(4)
+            .map(|word| u32::from_be_bytes(word.try_into().unwrap()))
+            .collect();
+        for t in 16..64 {
+            let w_t = shifted_xor(w[t - 2], SMALL_SIGMA_1)
+                .wrapping_add(w[t - 7])
+                .wrapping_add(shifted_xor(w[t - 15], SMALL_SIGMA_0))
+                .wrapping_add(w[t - 16]);
+            w.push(w_t);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+        for t in 0..64 {
+            let ch = (e & f) ^ (!e & g);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t1 = h
+                .wrapping_add(shifted_xor(e, BIG_SIGMA_1))
+                .wrapping_add(ch)
+                .wrapping_add(K[t])
+                .wrapping_add(w[t]);
+            let t2 = shifted_xor(a, BIG_SIGMA_0).wrapping_add(maj);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+
+        for (s, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *s = s.wrapping_add(v);
+        }
+    }
+
+    state
+}
+
+#[derive(Clone, Debug)]
+struct Chunk<F: PrimeField> {
+    offset: usize,
+    dense: AssignedCell<F, F>,
+    spread: AssignedCell<F, F>,
+}
+
+// A 32-bit word, range checked by its byte chunks.
+#[derive(Clone, Debug)]
+pub struct Word<F: PrimeField> {
+    pub dense: AssignedCell<F, F>,
+    bytes: Vec<Chunk<F>>,
+}
+
+impl<F: PrimeField> Word<F> {
+    pub fn value(&self) -> Value<u32> {
+        self.dense.value().map(|v| fe_to_u128(v) as u32)
+    }
+}
+
+fn i128_to_fe<F: PrimeField>(v: i128) -> F {
+    if v >= 0 {
+        F::from_u128(v as u128)
+    } else {
+        -F::from_u128(v.unsigned_abs())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Sha256Config<F> {
+    // chunk lookups
+    tag: Column<Fixed>,
+    dense: Column<Advice>,
+    spread: Column<Advice>,
+    q_lookup: Selector,
+
+    // linear combinations `acc_i = acc_{i-1} + coeff_i * value_i + const_i`
+    lc_value: Column<Advice>,
+    lc_coeff: Column<Fixed>,
+    lc_const: Column<Fixed>,
+    acc: Column<Advice>,
+    q_lc_first: Selector,
+    q_lc: Selector,
+    q_zero: Selector,
+
+    table: SpreadTable<F>,
+}
+
+// SHA-256 over spread words: the spread of a word has a zero between any two bits, so adding
+// up to three spread words keeps each bit position's sum in its own 2-bit slot. The even bits of
+// the sum are then the XOR of the inputs, and the odd bits their majority, which is enough for
+// Σ0, Σ1, σ0, σ1, Ch and Maj. Words are split into chunks of at most `MAX_CHUNK_BITS` bits, and
+// every chunk is looked up in a `SpreadTable` together with its spread.
+pub struct Sha256Chip<F: PrimeField> {
+    config: Sha256Config<F>,
+}
+
+impl<F: PrimeField> Sha256Chip<F> {
+    pub fn new(config: Sha256Config<F>) -> Self {
+        Self { config }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> Sha256Config<F> {
+        let tag = meta.fixed_column();
+        let dense = meta.advice_column();
+        let spread = meta.advice_column();
+        let q_lookup = meta.complex_selector();
+
+        let lc_value = meta.advice_column();
+        let lc_coeff = meta.fixed_column();
+        let lc_const = meta.fixed_column();
+        let acc = meta.advice_column();
+        let q_lc_first = meta.selector();
+        let q_lc = meta.selector();
+        let q_zero = meta.selector();
+
+        for col in [dense, spread, lc_value, acc] {
+            meta.enable_equality(col);
+        }
+
+        let table = SpreadTable::new(meta);
+        let (table_tag, table_dense, table_spread) = table.columns();
+
+        meta.lookup("spread", |meta| {
+            let q_lookup = meta.query_selector(q_lookup);
+            let tag = meta.query_fixed(tag, Rotation::cur());
+            let dense = meta.query_advice(dense, Rotation::cur());
+            let spread = meta.query_advice(spread, Rotation::cur());
+
+            vec![
+                (q_lookup.clone() * tag, table_tag),
+                (q_lookup.clone() * dense, table_dense),
+                (q_lookup * spread, table_spread),
+            ]
+        });
+
+        meta.create_gate("linear combination", |meta| {
+            let q_lc_first = meta.query_selector(q_lc_first);
+            let q_lc = meta.query_selector(q_lc);
+            let q_zero = meta.query_selector(q_zero);
+
+            let value = meta.query_advice(lc_value, Rotation::cur());
+            let coeff = meta.query_fixed(lc_coeff, Rotation::cur());
+            let constant = meta.query_fixed(lc_const, Rotation::cur());
+            let acc_cur = meta.query_advice(acc, Rotation::cur());
+            let acc_prev = meta.query_advice(acc, Rotation::prev());
+
+            let term = coeff * value + constant;
+
+            vec![
+                ("first", q_lc_first * (acc_cur.clone() - term.clone())),
+                ("next", q_lc * (acc_cur.clone() - acc_prev - term)),
+                ("zero", q_zero * acc_cur),
+            ]
+        });
+
+        Sha256Config {
+            tag,
+            dense,
+            spread,
+            q_lookup,
+            lc_value,
+            lc_coeff,
+            lc_const,
+            acc,
+            q_lc_first,
+            q_lc,
+            q_zero,
+            table,
+        }
+    }
+
+    pub fn load_table(&self, layouter: impl Layouter<F>) -> Result<(), plonk::Error> {
+        self.config.table.load(layouter)
+    }
+
+    // Witnesses and range checks the message bytes.
+    pub fn load_message(
+        &self,
+        mut layouter: impl Layouter<F>,
+        message: &[Value<u8>],
+    ) -> Result<Vec<AssignedCell<F, F>>, plonk::Error> {
+        message
+            .iter()
+            .enumerate()
+            .map(|(i, byte)| {
+                let chunks = self.lookup_chunks(
+                    layouter.namespace(|| format!("byte-{}", i)),
+                    byte.map(u64::from),
+                    &[0, 8],
+                )?;
+                Ok(chunks[0].dense.clone())
+            })
+            .collect()
+    }
+
+    // `message` must be range checked bytes, e.g. from `load_message`. Only the length of the
+    // message is fixed by the circuit.
+    pub fn digest(
+        &self,
+        mut layouter: impl Layouter<F>,
+        message: &[AssignedCell<F, F>],
+    ) -> Result<[Word<F>; 8], plonk::Error> {
+        // the padding only depends on the length
+        let padding = pad(&vec![0; message.len()]);
+
+        let mut state = Vec::with_capacity(8);
+        for (i, iv) in IV.iter().enumerate() {
+            state.push(self.constant_word(layouter.namespace(|| format!("iv-{}", i)), *iv)?);
+        }
+        let mut state: [Word<F>; 8] = state.try_into().unwrap();
+
+        for (i, block) in padding.chunks(BLOCK_BYTES).enumerate() {
+            let mut words = Vec::with_capacity(16);
+            for (j, word) in block.chunks(4).enumerate() {
+                let mut terms = vec![];
+                let mut constant = 0;
+                for (k, byte) in word.iter().enumerate() {
+                    let shift = 8 * (3 - k);
+                    match message.get(i * BLOCK_BYTES + j * 4 + k) {
+                        Some(cell) => terms.push((1i128 << shift, cell)),
+                        None => constant += (*byte as i128) << shift,
+                    }
+                }
+
+                let dense = self.lincomb(
+                    layouter.namespace(|| format!("block-{} word-{}", i, j)),
+                    &terms,
+                    constant,
+                    false,
+                )?;
+                let bytes = self.decompose(
+                    layouter.namespace(|| format!("block-{} word-{} bytes", i, j)),
+                    &dense,
+                    &BYTE_BOUNDARIES,
+                )?;
+                words.push(Word { dense, bytes });
+            }
+
+            state = self.compress(
+                layouter.namespace(|| format!("compress-{}", i)),
+                &state,
+                &words.try_into().unwrap(),
+            )?;
+        }
+
+        Ok(state)
+    }
+
+    pub fn compress(
+        &self,
+        mut layouter: impl Layouter<F>,
+        state: &[Word<F>; 8],
+        block: &[Word<F>; 16],
+    ) -> Result<[Word<F>; 8], plonk::Error> {
+        let mut w = block.to_vec();
+        for t in 16..64 {
+            let mut layouter = layouter.namespace(|| format!("schedule-{}", t));
+
+            let s0 = self.xor3(layouter.namespace(|| "σ0"), &w[t - 15], SMALL_SIGMA_0)?;
+            let s1 = self.xor3(layouter.namespace(|| "σ1"), &w[t - 2], SMALL_SIGMA_1)?;
+            let w_t = self.add(
+                layouter.namespace(|| "w"),
+                &[&s1.dense, &w[t - 7].dense, &s0.dense, &w[t - 16].dense],
+                0,
+            )?;
+            w.push(w_t);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state.clone();
+        for (t, w_t) in w.iter().enumerate() {
+            let mut layouter = layouter.namespace(|| format!("round-{}", t));
+
+            let s1 = self.xor3(layouter.namespace(|| "Σ1"), &e, BIG_SIGMA_1)?;
+            let (ch_0, ch_1) = self.ch(layouter.namespace(|| "ch"), &e, &f, &g)?;
+            let s0 = self.xor3(layouter.namespace(|| "Σ0"), &a, BIG_SIGMA_0)?;
+            let maj = self.maj(layouter.namespace(|| "maj"), &a, &b, &c)?;
+
+            // t1 = h + Σ1 + ch + K + w, t2 = Σ0 + maj
+            let t1 = [&h.dense, &s1.dense, &ch_0.dense, &ch_1.dense, &w_t.dense];
+            let new_e = self.add(
+                layouter.namespace(|| "d + t1"),
+                &[&[&d.dense][..], &t1[..]].concat(),
+                K[t],
+            )?;
+            let new_a = self.add(
+                layouter.namespace(|| "t1 + t2"),
+                &[&t1[..], &[&s0.dense, &maj.dense][..]].concat(),
+                K[t],
+            )?;
+
+            h = g;
+            g = f;
+            f = e;
+            e = new_e;
+            d = c;
+            c = b;
+            b = a;
+            a = new_a;
+        }
+
+        let mut next = Vec::with_capacity(8);
+        for (i, (s, v)) in state.iter().zip([a, b, c, d, e, f, g, h]).enumerate() {
+            next.push(self.add(
+                layouter.namespace(|| format!("state-{}", i)),
+                &[&s.dense, &v.dense],
+                0,
+            )?);
+        }
+
+        Ok(next.try_into().unwrap())
+    }
+
+    // (e & f) ^ (!e & g) as two words with disjoint bits, which sum up to the result.
+    fn ch(
+        &self,
+        mut layouter: impl Layouter<F>,
+        e: &Word<F>,
+        f: &Word<F>,
+        g: &Word<F>,
+    ) -> Result<(Word<F>, Word<F>), plonk::Error> {
+        let e_and_f = [spread_terms(e, 1), spread_terms(f, 1)].concat();
+        let (_, e_and_f) = self.spread_sum(layouter.namespace(|| "e & f"), e_and_f, 0)?;
+
+        // spread(!e) = spread(0xffffffff) - spread(e)
+        let not_e_and_g = [spread_terms(e, -1), spread_terms(g, 1)].concat();
+        let (_, not_e_and_g) = self.spread_sum(
+            layouter.namespace(|| "!e & g"),
+            not_e_and_g,
+            spread(u32::MAX as u64) as i128,
+        )?;
+
+        Ok((e_and_f, not_e_and_g))
+    }
+
+    fn maj(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &Word<F>,
+        b: &Word<F>,
+        c: &Word<F>,
+    ) -> Result<Word<F>, plonk::Error> {
+        let terms = [spread_terms(a, 1), spread_terms(b, 1), spread_terms(c, 1)].concat();
+        let (_, maj) = self.spread_sum(layouter, terms, 0)?;
+        Ok(maj)
+    }
+
+    // The XOR of three shifted copies of `word`.
+    fn xor3(
+        &self,
+        mut layouter: impl Layouter<F>,
+        word: &Word<F>,
+        shifts: [Shift; 3],
+    ) -> Result<Word<F>, plonk::Error> {
+        // no chunk may cross a shift amount, nor exceed the table
+        let mut boundaries = vec![0, 32];
+        boundaries.extend(shifts.iter().map(|shift| shift.amount()));
+        boundaries.sort_unstable();
+        boundaries.dedup();
+        let mut i = 1;
+        while i < boundaries.len() {
+            if boundaries[i] - boundaries[i - 1] > MAX_CHUNK_BITS {
+                boundaries.insert(i, boundaries[i - 1] + MAX_CHUNK_BITS);
+            }
+            i += 1;
+        }
+
+        let chunks = self.decompose(layouter.namespace(|| "chunks"), &word.dense, &boundaries)?;
+
+        let terms = shifts
+            .iter()
+            .flat_map(|shift| {
+                chunks.iter().filter_map(move |chunk| {
+                    let position = shift.position(chunk.offset)?;
+                    Some((1i128 << (2 * position), &chunk.spread))
+                })
+            })
+            .collect();
+
+        let (xor, _) = self.spread_sum(layouter.namespace(|| "xor"), terms, 0)?;
+        Ok(xor)
+    }
+
+    // Splits a sum of spread words into its even and odd bits.
+    fn spread_sum(
+        &self,
+        mut layouter: impl Layouter<F>,
+        terms: Vec<(i128, &AssignedCell<F, F>)>,
+        constant: i128,
+    ) -> Result<(Word<F>, Word<F>), plonk::Error> {
+        let sum = terms
+            .iter()
+            .fold(Value::known(constant), |acc, (coeff, cell)| {
+                acc.zip(cell.value())
+                    .map(|(acc, v)| acc + coeff * fe_to_u128(v) as i128)
+            })
+            .map(|sum| sum as u64);
+
+        let even = self.witness_word(layouter.namespace(|| "even"), sum.map(even_bits))?;
+        let odd =
+            self.witness_word(layouter.namespace(|| "odd"), sum.map(|s| even_bits(s >> 1)))?;
+
+        self.lincomb(
+            layouter.namespace(|| "sum"),
+            &[terms, spread_terms(&even, -1), spread_terms(&odd, -2)].concat(),
+            constant,
+            true,
+        )?;
+
+        Ok((even, odd))
+    }
+
+    // `sum(words) + constant` modulo 2^32, for at most 7 words.
+    fn add(
+        &self,
+        mut layouter: impl Layouter<F>,
+        words: &[&AssignedCell<F, F>],
+        constant: u32,
+    ) -> Result<Word<F>, plonk::Error> {
+        assert!(words.len() < 8);
+
+        let sum = words
+            .iter()
+            .fold(Value::known(constant as u64), |acc, word| {
+                acc.zip(word.value())
+                    .map(|(acc, v)| acc + fe_to_u128(v) as u64)
+            });
+
+        let result = self.witness_word(layouter.namespace(|| "result"), sum.map(|s| s as u32))?;
+        let carry = self.lookup_chunks(
+            layouter.namespace(|| "carry"),
+            sum.map(|s| s >> 32),
+            &[0, 3],
+        )?;
+
+        let terms: Vec<_> = words
+            .iter()
+            .map(|word| (1, *word))
+            .chain([(-1, &result.dense), (-(1 << 32), &carry[0].dense)])
+            .collect();
+        self.lincomb(layouter.namespace(|| "sum"), &terms, constant as i128, true)?;
+
+        Ok(result)
+    }
+
+    fn constant_word(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: u32,
+    ) -> Result<Word<F>, plonk::Error> {
+        let dense = self.lincomb(layouter.namespace(|| "constant"), &[], value as i128, false)?;
+        let bytes = self.decompose(layouter.namespace(|| "bytes"), &dense, &BYTE_BOUNDARIES)?;
+        Ok(Word { dense, bytes })
+    }
+
+    fn witness_word(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: Value<u32>,
+    ) -> Result<Word<F>, plonk::Error> {
+        let bytes = self.lookup_chunks(
+            layouter.namespace(|| "bytes"),
+            value.map(u64::from),
+            &BYTE_BOUNDARIES,
+        )?;
+        let terms: Vec<_> = bytes
+            .iter()
+            .map(|byte| (1i128 << byte.offset, &byte.dense))
+            .collect();
+        let dense = self.lincomb(layouter.namespace(|| "compose"), &terms, 0, false)?;
+        Ok(Word { dense, bytes })
+    }
+
+    // Splits `word` into chunks at `boundaries` and constrains them to add up to it.
+    fn decompose(
+        &self,
+        mut layouter: impl Layouter<F>,
+        word: &AssignedCell<F, F>,
+        boundaries: &[usize],
+    ) -> Result<Vec<Chunk<F>>, plonk::Error> {
+        let value = word.value().map(|v| fe_to_u128(v) as u64);
+        let chunks = self.lookup_chunks(layouter.namespace(|| "lookup"), value, boundaries)?;
+
+        let mut terms: Vec<_> = chunks
+            .iter()
+            .map(|chunk| (1i128 << chunk.offset, &chunk.dense))
+            .collect();
+        terms.push((-1, word));
+        self.lincomb(layouter.namespace(|| "compose"), &terms, 0, true)?;
+
+        Ok(chunks)
+    }
+
+    fn lookup_chunks(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: Value<u64>,
+        boundaries: &[usize],
+    ) -> Result<Vec<Chunk<F>>, plonk::Error> {
+        layouter.assign_region(
+            || "lookup chunks",
+            |mut region| {
+                boundaries
+                    .windows(2)
+                    .enumerate()
+                    .map(|(row, bounds)| {
+                        let (offset, bits) = (bounds[0], bounds[1] - bounds[0]);
+
+                        self.config.q_lookup.enable(&mut region, row)?;
+                        region.assign_fixed(
+                            || "tag",
+                            self.config.tag,
+                            row,
+                            || Value::known(F::from(bits as u64)),
+                        )?;
+
+                        let dense = value.map(|v| (v >> offset) & ((1 << bits) - 1));
+                        let spread = dense.map(|d| F::from(spread(d)));
+                        let dense = region.assign_advice(
+                            || "dense",
+                            self.config.dense,
+                            row,
+                            || dense.map(F::from),
+                        )?;
+                        let spread = region.assign_advice(
+                            || "spread",
+                            self.config.spread,
+                            row,
+                            || spread,
+                        )?;
+
+                        Ok(Chunk {
+                            offset,
+                            dense,
+                            spread,
+                        })
+                    })
+                    .collect()
+            },
+        )
+    }
+
+    // `sum(coeff * cell) + constant`, optionally constrained to be zero.
+    fn lincomb(
+        &self,
+        mut layouter: impl Layouter<F>,
+        terms: &[(i128, &AssignedCell<F, F>)],
+        constant: i128,
+        assert_zero: bool,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        layouter.assign_region(
+            || "linear combination",
+            |mut region| {
+                let rows = terms.len().max(1);
+
+                let mut acc = Value::known(F::ZERO);
+                let mut acc_cell = None;
+                for row in 0..rows {
+                    let (coeff, value) = match terms.get(row) {
+                        Some((coeff, cell)) => (
+                            i128_to_fe::<F>(*coeff),
+                            cell.copy_advice(|| "value", &mut region, self.config.lc_value, row)?,
+                        ),
+                        None => (
+                            F::ZERO,
+                            region.assign_advice(
+                                || "value",
+                                self.config.lc_value,
+                                row,
+                                || Value::known(F::ZERO),
+                            )?,
+                        ),
+                    };
+                    let constant = if row == 0 {
+                        i128_to_fe::<F>(constant)
+                    } else {
+                        F::ZERO
+                    };
+
+                    if row == 0 {
+                        self.config.q_lc_first.enable(&mut region, row)?;
+                    } else {
+                        self.config.q_lc.enable(&mut region, row)?;
+                    }
+                    region.assign_fixed(
+                        || "coeff",
+                        self.config.lc_coeff,
+                        row,
+                        || Value::known(coeff),
+                    )?;
+                    region.assign_fixed(
+                        || "const",
+                        self.config.lc_const,
+                        row,
+                        || Value::known(constant),
+                    )?;
+
+                    acc = acc + value.value().map(|v| *v * coeff) + Value::known(constant);
+                    acc_cell =
+                        Some(region.assign_advice(|| "acc", self.config.acc, row, || acc)?);
+                }
+
+                if assert_zero {
+                    self.config.q_zero.enable(&mut region, rows - 1)?;
+                }
+
+                Ok(acc_cell.unwrap())
+            },
+        )
+    }
+}
+
+// `coeff * spread(word)` as terms over the word's byte chunks.
+fn spread_terms<F: PrimeField>(word: &Word<F>, coeff: i128) -> Vec<(i128, &AssignedCell<F, F>)> {
+    word.bytes
+        .iter()
+        .map(|byte| (coeff << (2 * byte.offset), &byte.spread))
+        .collect()
+}
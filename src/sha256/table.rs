@@ -0,0 +1,79 @@
+use std::marker::PhantomData;
+
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::{Layouter, Value},
+    plonk::{self, ConstraintSystem, TableColumn},
+};
+
+pub const MAX_CHUNK_BITS: usize = 11;
+
+// Interleaves a zero bit above every bit of `v`, e.g. 0b111 -> 0b010101.
+pub fn spread(v: u64) -> u64 {
+    (0..32).fold(0, |acc, i| acc | (((v >> i) & 1) << (2 * i)))
+}
+
+// The inverse of `spread` on the even bits, the odd bits are dropped.
+pub fn even_bits(v: u64) -> u32 {
+    (0..32).fold(0, |acc, i| acc | ((((v >> (2 * i)) & 1) as u32) << i))
+}
+
+// Rows `(tag, dense, spread(dense))` for every `dense < 2^tag` and `tag <= MAX_CHUNK_BITS`, plus
+// `(0, 0, 0)` for disabled lookups. A chunk looked up with tag `n` is thus range checked to `n` bits.
+#[derive(Clone, Debug)]
+pub struct SpreadTable<F> {
+    tag: TableColumn,
+    dense: TableColumn,
+    spread: TableColumn,
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeField> SpreadTable<F> {
+    pub fn new(meta: &mut ConstraintSystem<F>) -> Self {
+        Self {
+            tag: meta.lookup_table_column(),
+            dense: meta.lookup_table_column(),
+            spread: meta.lookup_table_column(),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn columns(&self) -> (TableColumn, TableColumn, TableColumn) {
+        (self.tag, self.dense, self.spread)
+    }
+
+    pub fn load(&self, mut layouter: impl Layouter<F>) -> Result<(), plonk::Error> {
+        layouter.assign_table(
+            || "spread table",
+            |mut table| {
+                let rows = std::iter::once((0, 0)).chain(
+                    (1..=MAX_CHUNK_BITS)
+                        .flat_map(|bits| (0..(1u64 << bits)).map(move |v| (bits, v))),
+                );
+
+                for (row, (tag, dense)) in rows.enumerate() {
+                    table.assign_cell(
+                        || "tag",
+                        self.tag,
+                        row,
+                        || Value::known(F::from(tag as u64)),
+                    )?;
+                    table.assign_cell(
+                        || "dense",
+                        self.dense,
+                        row,
+                        || Value::known(F::from(dense)),
+                    )?;
+                    table.assign_cell(
+                        || "spread",
+                        self.spread,
+                        row,
+                        || Value::known(F::from(spread(dense))),
+                    )?;
+                }
+
+                Ok(())
+            },
+        )
+    }
+}
@@ -0,0 +1,199 @@
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    halo2curves::pasta::Fp,
+    plonk::{self, Advice, Circuit, Column, ConstraintSystem, Constraints, Instance, Selector},
+    poly::Rotation,
+};
+
+use crate::{
+    permutation::{PermutationChip, PermutationConfig},
+    poseidon::{poseidon_hash, PoseidonChip, PoseidonConfig},
+    range_check::{
+        decompose::{DecomposeChip, DecomposeConfig},
+        example2::RangeCheckTable,
+    },
+};
+
+// Entries are `u64`s, so both the sorted entries and their differences fit in 8 byte limbs.
+const NUM_LIMBS: usize = 8;
+
+#[derive(Clone)]
+pub struct SortConfig {
+    value: Column<Advice>,
+    diff: Column<Advice>,
+    q_diff: Selector,
+    instance: Column<Instance>,
+    decompose_config: DecomposeConfig<Fp>,
+    permutation_config: PermutationConfig<1>,
+    poseidon_config: PoseidonConfig,
+}
+
+// Proves that `output` is `input` sorted in ascending order. The public instances are the
+// Poseidon hashes `[H(input), H(output)]`.
+//
+// Range checking every output entry keeps the differences from wrapping around the modulus, and
+// the permutation argument carries the bound over to the input entries.
+pub struct SortCircuit<const N: usize> {
+    pub input: [Value<Fp>; N],
+    pub output: [Value<Fp>; N],
+}
+
+impl<const N: usize> Default for SortCircuit<N> {
+    fn default() -> Self {
+        Self {
+            input: [Value::unknown(); N],
+            output: [Value::unknown(); N],
+        }
+    }
+}
+
+impl<const N: usize> SortCircuit<N> {
+    pub fn new(input: [u64; N]) -> Self {
+        let mut output = input;
+        output.sort_unstable();
+
+        Self {
+            input: input.map(|v| Value::known(Fp::from(v))),
+            output: output.map(|v| Value::known(Fp::from(v))),
+        }
+    }
+
+    pub fn instances(input: [u64; N]) -> Vec<Fp> {
+        let mut output = input;
+        output.sort_unstable();
+
+        vec![
+            poseidon_hash(input.map(Fp::from)),
+            poseidon_hash(output.map(Fp::from)),
+        ]
+    }
+
+    fn load(
+        config: &SortConfig,
+        mut layouter: impl Layouter<Fp>,
+        values: &[Value<Fp>; N],
+    ) -> Result<[AssignedCell<Fp, Fp>; N], plonk::Error> {
+        layouter.assign_region(
+            || "load values",
+            |mut region| {
+                let mut cells = Vec::with_capacity(N);
+                for (i, value) in values.iter().enumerate() {
+                    cells.push(region.assign_advice(
+                        || format!("value-{}", i),
+                        config.value,
+                        i,
+                        || *value,
+                    )?);
+                }
+                Ok(cells.try_into().unwrap())
+            },
+        )
+    }
+
+    // value       | diff
+    // output_0    | output_1 - output_0
+    // output_1    | output_2 - output_1
+    // ...
+    fn assign_diffs(
+        config: &SortConfig,
+        mut layouter: impl Layouter<Fp>,
+        output: &[AssignedCell<Fp, Fp>; N],
+    ) -> Result<Vec<AssignedCell<Fp, Fp>>, plonk::Error> {
+        layouter.assign_region(
+            || "adjacent differences",
+            |mut region| {
+                for (i, cell) in output.iter().enumerate() {
+                    cell.copy_advice(|| "output", &mut region, config.value, i)?;
+                }
+
+                output
+                    .windows(2)
+                    .enumerate()
+                    .map(|(i, pair)| {
+                        config.q_diff.enable(&mut region, i)?;
+
+                        let diff = pair[1].value().cloned() - pair[0].value();
+                        region.assign_advice(|| format!("diff-{}", i), config.diff, i, || diff)
+                    })
+                    .collect()
+            },
+        )
+    }
+}
+
+impl<const N: usize> Circuit<Fp> for SortCircuit<N> {
+    type Config = SortConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let value = meta.advice_column();
+        let diff = meta.advice_column();
+        let q_diff = meta.selector();
+        let instance = meta.instance_column();
+
+        meta.enable_equality(value);
+        meta.enable_equality(diff);
+        meta.enable_equality(instance);
+
+        meta.create_gate("adjacent difference", |meta| {
+            let q_diff = meta.query_selector(q_diff);
+            let cur = meta.query_advice(value, Rotation::cur());
+            let next = meta.query_advice(value, Rotation::next());
+            let diff = meta.query_advice(diff, Rotation::cur());
+
+            Constraints::with_selector(q_diff, [diff - (next - cur)])
+        });
+
+        let table = RangeCheckTable::new(meta);
+
+        SortConfig {
+            value,
+            diff,
+            q_diff,
+            instance,
+            decompose_config: DecomposeChip::configure(meta, table),
+            permutation_config: PermutationChip::configure(meta),
+            poseidon_config: PoseidonChip::<N>::configure(meta),
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), plonk::Error> {
+        let decompose_chip = DecomposeChip::new(config.decompose_config.clone());
+        let permutation_chip = PermutationChip::new(config.permutation_config.clone());
+        let poseidon_chip = PoseidonChip::<N>::new(config.poseidon_config.clone());
+
+        decompose_chip.load_table(layouter.namespace(|| "load table"))?;
+
+        let input = Self::load(&config, layouter.namespace(|| "load input"), &self.input)?;
+        let output = Self::load(&config, layouter.namespace(|| "load output"), &self.output)?;
+
+        let diffs = Self::assign_diffs(&config, layouter.namespace(|| "diffs"), &output)?;
+        for (i, cell) in output.iter().chain(diffs.iter()).enumerate() {
+            decompose_chip.range_check(
+                layouter.namespace(|| format!("range check {}", i)),
+                cell,
+                NUM_LIMBS,
+            )?;
+        }
+
+        permutation_chip.assert_permutation(
+            layouter.namespace(|| "permutation"),
+            &input,
+            &output,
+        )?;
+
+        let input_hash = poseidon_chip.hash(layouter.namespace(|| "hash input"), input)?;
+        let output_hash = poseidon_chip.hash(layouter.namespace(|| "hash output"), output)?;
+
+        layouter.constrain_instance(input_hash.cell(), config.instance, 0)?;
+        layouter.constrain_instance(output_hash.cell(), config.instance, 1)
+    }
+}
@@ -0,0 +1,204 @@
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter},
+    plonk::{self, Advice, Column, ConstraintSystem, Constraints, Expression, Selector},
+    poly::Rotation,
+};
+
+use crate::is_zero::{IsZeroChip, IsZeroConfig};
+
+// `p - 1` as little-endian u64 limbs.
+fn modulus_minus_one<F: PrimeField>() -> Vec<u64> {
+    let hex = F::MODULUS.trim_start_matches("0x");
+    let mut limbs: Vec<u64> = hex
+        .as_bytes()
+        .rchunks(16)
+        .map(|chunk| u64::from_str_radix(std::str::from_utf8(chunk).unwrap(), 16).unwrap())
+        .collect();
+    // p is odd, so there is no borrow
+    limbs[0] -= 1;
+    limbs
+}
+
+fn shr(limbs: &[u64], n: u32) -> Vec<u64> {
+    assert!(n < 64);
+    if n == 0 {
+        return limbs.to_vec();
+    }
+
+    (0..limbs.len())
+        .map(|i| {
+            let hi = limbs.get(i + 1).map_or(0, |hi| hi << (64 - n));
+            (limbs[i] >> n) | hi
+        })
+        .collect()
+}
+
+fn add_one(limbs: &[u64]) -> Vec<u64> {
+    let mut limbs = limbs.to_vec();
+    for limb in limbs.iter_mut() {
+        let (sum, carry) = limb.overflowing_add(1);
+        *limb = sum;
+        if !carry {
+            break;
+        }
+    }
+    limbs
+}
+
+// Euler's criterion, zero counts as a residue.
+pub fn is_quadratic_residue<F: PrimeField>(x: &F) -> bool {
+    bool::from(x.is_zero()) || x.pow_vartime(shr(&modulus_minus_one::<F>(), 1)) == F::ONE
+}
+
+// Tonelli–Shanks, with `p - 1 = q * 2^S` and `F::ROOT_OF_UNITY = g^q`.
+pub fn tonelli_shanks<F: PrimeField>(x: &F) -> Option<F> {
+    if bool::from(x.is_zero()) {
+        return Some(F::ZERO);
+    }
+
+    let mut q = modulus_minus_one::<F>();
+    for _ in 0..F::S / 32 {
+        q = shr(&q, 32);
+    }
+    q = shr(&q, F::S % 32);
+
+    let mut m = F::S;
+    let mut c = F::ROOT_OF_UNITY;
+    let mut t = x.pow_vartime(&q);
+    let mut r = x.pow_vartime(shr(&add_one(&q), 1));
+
+    while t != F::ONE {
+        // the least i with t^(2^i) == 1
+        let mut i = 0;
+        let mut t2i = t;
+        while t2i != F::ONE {
+            t2i = t2i.square();
+            i += 1;
+            if i == m {
+                return None;
+            }
+        }
+
+        let mut b = c;
+        for _ in 0..(m - i - 1) {
+            b = b.square();
+        }
+
+        m = i;
+        c = b.square();
+        t *= c;
+        r *= b;
+    }
+
+    Some(r)
+}
+
+#[derive(Clone)]
+pub struct SqrtConfig<F> {
+    x: Column<Advice>,
+    s: Column<Advice>,
+    is_qr: Column<Advice>,
+    sel: Selector,
+
+    is_zero_config: IsZeroConfig<F>,
+}
+
+// Witnesses `s` with `s^2 = x` if `x` is a quadratic residue, and `s^2 = g * x` otherwise, where
+// `g` is the multiplicative generator and thus a non-residue. Since `g * x` is a non-residue for
+// any non-zero residue `x`, the prover can't lie about `is_qr`, except for zero which is forced to
+// be a residue with `IsZeroChip`.
+pub struct SqrtChip<F> {
+    config: SqrtConfig<F>,
+}
+
+impl<F: PrimeField> SqrtChip<F> {
+    pub fn new(config: SqrtConfig<F>) -> Self {
+        Self { config }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> SqrtConfig<F> {
+        let x = meta.advice_column();
+        let s = meta.advice_column();
+        let is_qr = meta.advice_column();
+        let sel = meta.selector();
+
+        meta.enable_equality(x);
+        meta.enable_equality(s);
+        meta.enable_equality(is_qr);
+
+        let is_zero_config = IsZeroChip::configure(
+            meta,
+            |meta| meta.query_selector(sel),
+            |meta| meta.query_advice(x, Rotation::cur()),
+        );
+
+        meta.create_gate("sqrt", |meta| {
+            let sel = meta.query_selector(sel);
+            let x = meta.query_advice(x, Rotation::cur());
+            let s = meta.query_advice(s, Rotation::cur());
+            let is_qr = meta.query_advice(is_qr, Rotation::cur());
+
+            let one = || Expression::Constant(F::ONE);
+            let g = Expression::Constant(F::MULTIPLICATIVE_GENERATOR);
+
+            let square = is_qr.clone() * x.clone() + (one() - is_qr.clone()) * g * x;
+
+            Constraints::with_selector(
+                sel,
+                [
+                    ("s * s = square", s.clone() * s - square),
+                    ("is_qr is bool", is_qr.clone() * (one() - is_qr.clone())),
+                    ("zero is a residue", is_zero_config.expr() * (one() - is_qr)),
+                ],
+            )
+        });
+
+        SqrtConfig {
+            x,
+            s,
+            is_qr,
+            sel,
+            is_zero_config,
+        }
+    }
+
+    // Returns `(s, is_qr)`.
+    pub fn sqrt(
+        &self,
+        mut layouter: impl Layouter<F>,
+        x: &AssignedCell<F, F>,
+    ) -> Result<(AssignedCell<F, F>, AssignedCell<F, F>), plonk::Error> {
+        let is_zero_chip = IsZeroChip::new(self.config.is_zero_config.clone());
+
+        layouter.assign_region(
+            || "sqrt",
+            |mut region| {
+                self.config.sel.enable(&mut region, 0)?;
+
+                let x = x.copy_advice(|| "x", &mut region, self.config.x, 0)?;
+                is_zero_chip.assign(&mut region, 0, x.value().copied())?;
+
+                let is_qr = x.value().map(is_quadratic_residue);
+                let s = x.value().zip(is_qr).map(|(x, is_qr)| {
+                    let square = if is_qr {
+                        *x
+                    } else {
+                        *x * F::MULTIPLICATIVE_GENERATOR
+                    };
+                    tonelli_shanks(&square).unwrap()
+                });
+
+                let s = region.assign_advice(|| "s", self.config.s, 0, || s)?;
+                let is_qr = region.assign_advice(
+                    || "is qr",
+                    self.config.is_qr,
+                    0,
+                    || is_qr.map(|is_qr| F::from(is_qr as u64)),
+                )?;
+
+                Ok((s, is_qr))
+            },
+        )
+    }
+}
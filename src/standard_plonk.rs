@@ -0,0 +1,245 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter, Region, Value},
+    plonk::{self, Advice, Column, ConstraintSystem, Fixed, Instance},
+    poly::Rotation,
+};
+
+#[derive(Clone, Debug)]
+pub struct StandardPlonkConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    c: Column<Advice>,
+
+    q_a: Column<Fixed>,
+    q_b: Column<Fixed>,
+    q_c: Column<Fixed>,
+    q_m: Column<Fixed>,
+    q_o: Column<Fixed>,
+
+    instance: Column<Instance>,
+}
+
+// The coefficients of a single `q_a*a + q_b*b + q_m*a*b + q_c + q_o*c = 0` row.
+#[derive(Clone, Copy, Default)]
+struct Coeffs<F> {
+    q_a: F,
+    q_b: F,
+    q_c: F,
+    q_m: F,
+    q_o: F,
+}
+
+pub struct StandardPlonkChip<F> {
+    config: StandardPlonkConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> StandardPlonkChip<F> {
+    pub fn new(config: StandardPlonkConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> StandardPlonkConfig {
+        let [a, b, c] = [(); 3].map(|_| meta.advice_column());
+        let [q_a, q_b, q_c, q_m, q_o] = [(); 5].map(|_| meta.fixed_column());
+        let instance = meta.instance_column();
+
+        for col in [a, b, c] {
+            meta.enable_equality(col);
+        }
+        meta.enable_equality(instance);
+
+        meta.create_gate("standard plonk", |meta| {
+            let [a, b, c] = [a, b, c].map(|col| meta.query_advice(col, Rotation::cur()));
+            let [q_a, q_b, q_c, q_m, q_o] =
+                [q_a, q_b, q_c, q_m, q_o].map(|col| meta.query_fixed(col, Rotation::cur()));
+
+            vec![q_a * a.clone() + q_b * b.clone() + q_m * a * b + q_c + q_o * c]
+        });
+
+        StandardPlonkConfig {
+            a,
+            b,
+            c,
+            q_a,
+            q_b,
+            q_c,
+            q_m,
+            q_o,
+            instance,
+        }
+    }
+
+    pub fn load_private(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: Value<F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        layouter.assign_region(
+            || "load private",
+            |mut region| region.assign_advice(|| "private", self.config.a, 0, || value),
+        )
+    }
+
+    pub fn constant(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: F,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        layouter.assign_region(
+            || "constant",
+            |mut region| {
+                // a - value = 0
+                self.assign_coeffs(
+                    &mut region,
+                    Coeffs {
+                        q_a: F::ONE,
+                        q_c: -value,
+                        ..Default::default()
+                    },
+                )?;
+                region.assign_advice(|| "constant", self.config.a, 0, || Value::known(value))
+            },
+        )
+    }
+
+    pub fn add(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        // a + b - c = 0
+        let coeffs = Coeffs {
+            q_a: F::ONE,
+            q_b: F::ONE,
+            q_o: -F::ONE,
+            ..Default::default()
+        };
+        self.assign_binary(layouter, "add", a, b, coeffs, |a, b| a + b)
+    }
+
+    pub fn sub(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        // a - b - c = 0
+        let coeffs = Coeffs {
+            q_a: F::ONE,
+            q_b: -F::ONE,
+            q_o: -F::ONE,
+            ..Default::default()
+        };
+        self.assign_binary(layouter, "sub", a, b, coeffs, |a, b| a - b)
+    }
+
+    pub fn mul(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        // a * b - c = 0
+        let coeffs = Coeffs {
+            q_m: F::ONE,
+            q_o: -F::ONE,
+            ..Default::default()
+        };
+        self.assign_binary(layouter, "mul", a, b, coeffs, |a, b| a * b)
+    }
+
+    // The circuit is not satisfied if `a` is zero.
+    pub fn inverse(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        layouter.assign_region(
+            || "inverse",
+            |mut region| {
+                // a * a_inv - 1 = 0
+                self.assign_coeffs(
+                    &mut region,
+                    Coeffs {
+                        q_m: F::ONE,
+                        q_c: -F::ONE,
+                        ..Default::default()
+                    },
+                )?;
+
+                let a = a.copy_advice(|| "a", &mut region, self.config.a, 0)?;
+                let a_inv = a.value().map(|a| a.invert().unwrap_or(F::ZERO));
+                region.assign_advice(|| "a inv", self.config.b, 0, || a_inv)
+            },
+        )
+    }
+
+    pub fn assert_equal(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+    ) -> Result<(), plonk::Error> {
+        layouter.assign_region(
+            || "assert equal",
+            |mut region| region.constrain_equal(a.cell(), b.cell()),
+        )
+    }
+
+    pub fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        cell: &AssignedCell<F, F>,
+        row: usize,
+    ) -> Result<(), plonk::Error> {
+        layouter.constrain_instance(cell.cell(), self.config.instance, row)
+    }
+
+    fn assign_binary(
+        &self,
+        mut layouter: impl Layouter<F>,
+        name: &str,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+        coeffs: Coeffs<F>,
+        op: impl Fn(F, F) -> F,
+    ) -> Result<AssignedCell<F, F>, plonk::Error> {
+        layouter.assign_region(
+            || name,
+            |mut region| {
+                self.assign_coeffs(&mut region, coeffs)?;
+
+                let a = a.copy_advice(|| "a", &mut region, self.config.a, 0)?;
+                let b = b.copy_advice(|| "b", &mut region, self.config.b, 0)?;
+
+                let c = a.value().zip(b.value()).map(|(a, b)| op(*a, *b));
+                region.assign_advice(|| "c", self.config.c, 0, || c)
+            },
+        )
+    }
+
+    fn assign_coeffs(
+        &self,
+        region: &mut Region<'_, F>,
+        coeffs: Coeffs<F>,
+    ) -> Result<(), plonk::Error> {
+        for (name, col, value) in [
+            ("q_a", self.config.q_a, coeffs.q_a),
+            ("q_b", self.config.q_b, coeffs.q_b),
+            ("q_c", self.config.q_c, coeffs.q_c),
+            ("q_m", self.config.q_m, coeffs.q_m),
+            ("q_o", self.config.q_o, coeffs.q_o),
+        ] {
+            region.assign_fixed(|| name, col, 0, || Value::known(value))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    plonk::{
+        self, Advice, Circuit, Column, ConstraintSystem, Constraints, Expression, Instance,
+        Selector,
+    },
+    poly::Rotation,
+};
+
+use crate::{
+    permutation::{PermutationChip, PermutationConfig},
+    range_check::example2::RangeCheckTable,
+};
+
+pub type Grid = [[u8; 9]; 9];
+
+// Indices of the 27 rows, columns and boxes.
+fn groups() -> Vec<[(usize, usize); 9]> {
+    let rows = (0..9).map(|r| std::array::from_fn(|i| (r, i)));
+    let cols = (0..9).map(|c| std::array::from_fn(|i| (i, c)));
+    let boxes = (0..9).map(|b| std::array::from_fn(|i| (b / 3 * 3 + i / 3, b % 3 * 3 + i % 3)));
+    rows.chain(cols).chain(boxes).collect()
+}
+
+pub fn is_solution(puzzle: &Grid, solution: &Grid) -> bool {
+    let clues_match = (0..81).all(|i| {
+        let clue = puzzle[i / 9][i % 9];
+        clue == 0 || clue == solution[i / 9][i % 9]
+    });
+
+    let groups_valid = groups().iter().all(|group| {
+        let mut digits = group.map(|(r, c)| solution[r][c]);
+        digits.sort_unstable();
+        digits == [1, 2, 3, 4, 5, 6, 7, 8, 9]
+    });
+
+    clues_match && groups_valid
+}
+
+// Backtracking over the empty cells, zero being empty.
+pub fn solve(puzzle: &Grid) -> Option<Grid> {
+    fn allowed(grid: &Grid, r: usize, c: usize, digit: u8) -> bool {
+        (0..9).all(|i| {
+            grid[r][i] != digit
+                && grid[i][c] != digit
+                && grid[r / 3 * 3 + i / 3][c / 3 * 3 + i % 3] != digit
+        })
+    }
+
+    fn go(grid: &mut Grid, cell: usize) -> bool {
+        if cell == 81 {
+            return true;
+        }
+
+        let (r, c) = (cell / 9, cell % 9);
+        if grid[r][c] != 0 {
+            return go(grid, cell + 1);
+        }
+
+        for digit in 1..=9 {
+            if allowed(grid, r, c, digit) {
+                grid[r][c] = digit;
+                if go(grid, cell + 1) {
+                    return true;
+                }
+            }
+        }
+        grid[r][c] = 0;
+
+        false
+    }
+
+    let mut grid = *puzzle;
+    (go(&mut grid, 0) && is_solution(puzzle, &grid)).then_some(grid)
+}
+
+#[derive(Clone)]
+pub struct SudokuConfig<F> {
+    cell: Column<Advice>,
+    clue: Column<Advice>,
+    digits: Column<Advice>,
+    q_cell: Selector,
+    instance: Column<Instance>,
+    table: RangeCheckTable<F, 9>,
+    permutation_config: PermutationConfig<1>,
+}
+
+// The 81 clues are the public instances in row-major order, with zero for an empty cell. They
+// are copied next to the solution:
+//
+// cell | clue
+//  5   |  5
+//  3   |  0
+// ...
+//
+// Each cell equals its clue when there's one, `cell - 1` is looked up in `[0, 9)`, and each row,
+// column and box is a permutation of the digits 1 to 9.
+#[derive(Default)]
+pub struct SudokuCircuit {
+    pub solution: Value<Grid>,
+}
+
+impl SudokuCircuit {
+    pub fn instances<F: PrimeField>(puzzle: &Grid) -> Vec<F> {
+        puzzle
+            .iter()
+            .flatten()
+            .map(|clue| F::from(*clue as u64))
+            .collect()
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for SudokuCircuit {
+    type Config = SudokuConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let cell = meta.advice_column();
+        let clue = meta.advice_column();
+        let digits = meta.advice_column();
+        let constants = meta.fixed_column();
+        let q_cell = meta.complex_selector();
+        let instance = meta.instance_column();
+        let table = RangeCheckTable::new(meta);
+
+        meta.enable_equality(cell);
+        meta.enable_equality(clue);
+        meta.enable_equality(instance);
+        meta.enable_equality(digits);
+        meta.enable_constant(constants);
+
+        meta.create_gate("sudoku clue", |meta| {
+            let q_cell = meta.query_selector(q_cell);
+            let cell = meta.query_advice(cell, Rotation::cur());
+            let clue = meta.query_advice(clue, Rotation::cur());
+
+            Constraints::with_selector(q_cell, [clue.clone() * (cell - clue)])
+        });
+
+        meta.lookup("sudoku digit", |meta| {
+            let q_cell = meta.query_selector(q_cell);
+            let cell = meta.query_advice(cell, Rotation::cur());
+
+            vec![(
+                q_cell * (cell - Expression::Constant(F::ONE)),
+                *table.table_column(),
+            )]
+        });
+
+        SudokuConfig {
+            cell,
+            clue,
+            digits,
+            q_cell,
+            instance,
+            table,
+            permutation_config: PermutationChip::configure(meta),
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        config.table.load(layouter.namespace(|| "load table"))?;
+
+        let cells = layouter.assign_region(
+            || "grid",
+            |mut region| {
+                (0..81)
+                    .map(|i| {
+                        config.q_cell.enable(&mut region, i)?;
+                        region.assign_advice_from_instance(
+                            || format!("clue-{}", i),
+                            config.instance,
+                            i,
+                            config.clue,
+                            i,
+                        )?;
+
+                        let value = self
+                            .solution
+                            .map(|solution| F::from(solution[i / 9][i % 9] as u64));
+                        region.assign_advice(|| format!("cell-{}", i), config.cell, i, || value)
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            },
+        )?;
+
+        let digits = layouter.assign_region(
+            || "digits",
+            |mut region| {
+                (1..=9)
+                    .map(|digit| {
+                        region.assign_advice_from_constant(
+                            || format!("digit-{}", digit),
+                            config.digits,
+                            digit - 1,
+                            F::from(digit as u64),
+                        )
+                    })
+                    .collect::<Result<Vec<AssignedCell<F, F>>, _>>()
+            },
+        )?;
+
+        let permutation_chip = PermutationChip::new(config.permutation_config.clone());
+        for (i, group) in groups().iter().enumerate() {
+            let group: Vec<_> = group
+                .iter()
+                .map(|(r, c)| cells[r * 9 + c].clone())
+                .collect();
+            permutation_chip.assert_permutation(
+                layouter.namespace(|| format!("group {}", i)),
+                &group,
+                &digits,
+            )?;
+        }
+
+        Ok(())
+    }
+}
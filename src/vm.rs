@@ -0,0 +1,492 @@
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    plonk::{
+        self, Advice, Circuit, Column, ConstraintSystem, Constraints, Expression, Instance,
+        Selector, TableColumn, VirtualCells,
+    },
+    poly::Rotation,
+};
+
+use crate::is_zero::{IsZeroChip, IsZeroConfig};
+
+pub const STACK_DEPTH: usize = 4;
+const NUM_OPS: usize = 2 * STACK_DEPTH + 5;
+
+// `Dup(n)` and `Swap(n)` count from the top of the stack as in the EVM: `Dup(1)` duplicates the
+// top and `Swap(1)` swaps the two topmost entries. `Sub` computes `top - second`, and `Jumpi`
+// pops the condition and jumps when it's non-zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instr {
+    Push(u64),
+    Add,
+    Mul,
+    Sub,
+    Dup(usize),
+    Swap(usize),
+    Jumpi(usize),
+    Halt,
+}
+
+impl Instr {
+    // Index of the instruction's flag column. The opcode is `index + 1`, keeping zero free for
+    // the unused rows of the program table.
+    fn index(&self) -> usize {
+        match *self {
+            Instr::Push(_) => 0,
+            Instr::Add => 1,
+            Instr::Mul => 2,
+            Instr::Sub => 3,
+            Instr::Dup(n) => {
+                assert!((1..=STACK_DEPTH).contains(&n));
+                3 + n
+            }
+            Instr::Swap(n) => {
+                assert!((1..STACK_DEPTH).contains(&n));
+                3 + STACK_DEPTH + n
+            }
+            Instr::Jumpi(_) => 2 * STACK_DEPTH + 3,
+            Instr::Halt => 2 * STACK_DEPTH + 4,
+        }
+    }
+
+    fn imm<F: PrimeField>(&self) -> F {
+        match *self {
+            Instr::Push(v) => F::from(v),
+            Instr::Jumpi(target) => F::from(target as u64),
+            _ => F::ZERO,
+        }
+    }
+}
+
+// The state before executing the instruction at `pc`. The stack is a fixed window of
+// `STACK_DEPTH` entries with the top first; popped entries are refilled with zeros.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct State<F> {
+    pub pc: usize,
+    pub stack: [F; STACK_DEPTH],
+}
+
+impl<F: PrimeField> State<F> {
+    fn step(&self, instr: Instr) -> Option<Self> {
+        let s = self.stack;
+        let push = |v: F| {
+            if s[STACK_DEPTH - 1] != F::ZERO {
+                return None;
+            }
+            let mut stack = [F::ZERO; STACK_DEPTH];
+            stack[0] = v;
+            stack[1..].copy_from_slice(&s[..STACK_DEPTH - 1]);
+            Some(stack)
+        };
+        let pop2 = |v: F| {
+            let mut stack = [F::ZERO; STACK_DEPTH];
+            stack[0] = v;
+            stack[1..STACK_DEPTH - 1].copy_from_slice(&s[2..]);
+            stack
+        };
+
+        let (pc, stack) = match instr {
+            Instr::Push(v) => (self.pc + 1, push(F::from(v))?),
+            Instr::Add => (self.pc + 1, pop2(s[0] + s[1])),
+            Instr::Mul => (self.pc + 1, pop2(s[0] * s[1])),
+            Instr::Sub => (self.pc + 1, pop2(s[0] - s[1])),
+            Instr::Dup(n) => (self.pc + 1, push(s[n - 1])?),
+            Instr::Swap(n) => {
+                let mut stack = s;
+                stack.swap(0, n);
+                (self.pc + 1, stack)
+            }
+            Instr::Jumpi(target) => {
+                let mut stack = [F::ZERO; STACK_DEPTH];
+                stack[..STACK_DEPTH - 1].copy_from_slice(&s[1..]);
+                let pc = if s[0] == F::ZERO { self.pc + 1 } else { target };
+                (pc, stack)
+            }
+            Instr::Halt => (self.pc, s),
+        };
+
+        Some(Self { pc, stack })
+    }
+}
+
+// Runs `program` for exactly `steps` steps and returns the `steps + 1` states, or `None` if the
+// stack overflows, `pc` leaves the program or the program hasn't halted by then.
+pub fn execute<F: PrimeField>(program: &[Instr], steps: usize) -> Option<Vec<State<F>>> {
+    let mut trace = vec![State {
+        pc: 0,
+        stack: [F::ZERO; STACK_DEPTH],
+    }];
+
+    for _ in 0..steps {
+        let state = trace.last().unwrap();
+        let next = state.step(*program.get(state.pc)?)?;
+        trace.push(next);
+    }
+
+    let last = trace.last().unwrap();
+    (program.get(last.pc)? == &Instr::Halt).then_some(trace)
+}
+
+// Leaves `F(n + 1)` on top of the stack, for the sequence `F(0) = a, F(1) = b`. The loop body
+// runs at least once, so `n` must be positive.
+pub fn fibonacci_program(a: u64, b: u64, n: u64) -> Vec<Instr> {
+    use Instr::*;
+
+    const LOOP: usize = 3;
+
+    vec![
+        // [n, b, a]
+        Push(a),
+        Push(b),
+        Push(n),
+        // [c, b, a] -> [c - 1, a + b, b]
+        Push(1),
+        Swap(1),
+        Sub,
+        Swap(2),
+        Dup(2),
+        Add,
+        Swap(1),
+        Swap(2),
+        Dup(1),
+        Jumpi(LOOP),
+        // [0, b, a] -> [b, 0, a]
+        Swap(1),
+        Halt,
+    ]
+}
+
+#[derive(Clone)]
+pub struct VmConfig<F> {
+    pc: Column<Advice>,
+    imm: Column<Advice>,
+    stack: [Column<Advice>; STACK_DEPTH],
+    flags: [Column<Advice>; NUM_OPS],
+    q_first: Selector,
+    q_row: Selector,
+    q_step: Selector,
+    q_last: Selector,
+    program: [TableColumn; 3],
+    is_zero_config: IsZeroConfig<F>,
+    instance: Column<Instance>,
+}
+
+// One row per state, with one-hot flags selecting the instruction at `pc`:
+//
+// pc | imm | s_0 .. s_3 | flag_push .. flag_halt | 1 / s_0
+//
+// Every row's `(pc, opcode, imm)` is looked up in the program table, and `q_step` constrains the
+// transition to the next row according to the flags. The final state must be at a `Halt`, and
+// its top of the stack is the public output.
+pub struct VmCircuit<F> {
+    pub program: Vec<Instr>,
+    pub steps: usize,
+    pub trace: Value<Vec<State<F>>>,
+}
+
+impl<F: PrimeField> VmCircuit<F> {
+    pub fn new(program: Vec<Instr>, steps: usize) -> Option<Self> {
+        let trace = execute(&program, steps)?;
+
+        Some(Self {
+            program,
+            steps,
+            trace: Value::known(trace),
+        })
+    }
+
+    pub fn output(&self) -> Value<F> {
+        self.trace
+            .as_ref()
+            .map(|trace| trace.last().unwrap().stack[0])
+    }
+
+    fn load_program(
+        &self,
+        config: &VmConfig<F>,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        layouter.assign_table(
+            || "program",
+            |mut table| {
+                let rows = [(F::ZERO, F::ZERO, F::ZERO)].into_iter().chain(
+                    self.program.iter().enumerate().map(|(pc, instr)| {
+                        (
+                            F::from(pc as u64),
+                            F::from(instr.index() as u64 + 1),
+                            instr.imm(),
+                        )
+                    }),
+                );
+
+                for (offset, (pc, opcode, imm)) in rows.enumerate() {
+                    let [pc_col, opcode_col, imm_col] = config.program;
+                    table.assign_cell(|| "pc", pc_col, offset, || Value::known(pc))?;
+                    table.assign_cell(|| "opcode", opcode_col, offset, || Value::known(opcode))?;
+                    table.assign_cell(|| "imm", imm_col, offset, || Value::known(imm))?;
+                }
+
+                Ok(())
+            },
+        )
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for VmCircuit<F> {
+    type Config = VmConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            program: self.program.clone(),
+            steps: self.steps,
+            trace: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let pc = meta.advice_column();
+        let imm = meta.advice_column();
+        let stack = [(); STACK_DEPTH].map(|_| meta.advice_column());
+        let flags = [(); NUM_OPS].map(|_| meta.advice_column());
+        let q_first = meta.selector();
+        let q_row = meta.complex_selector();
+        let q_step = meta.selector();
+        let q_last = meta.selector();
+        let program = [(); 3].map(|_| meta.lookup_table_column());
+        let instance = meta.instance_column();
+
+        meta.enable_equality(stack[0]);
+        meta.enable_equality(instance);
+
+        let is_zero_config = IsZeroChip::configure(
+            meta,
+            |meta| {
+                meta.query_selector(q_step)
+                    * meta.query_advice(flags[Instr::Jumpi(0).index()], Rotation::cur())
+            },
+            |meta| meta.query_advice(stack[0], Rotation::cur()),
+        );
+
+        meta.create_gate("vm first row", |meta| {
+            let q_first = meta.query_selector(q_first);
+            let pc = meta.query_advice(pc, Rotation::cur());
+            let stack = stack.map(|column| meta.query_advice(column, Rotation::cur()));
+
+            Constraints::with_selector(q_first, std::iter::once(pc).chain(stack))
+        });
+
+        meta.create_gate("vm flags", |meta| {
+            let q_row = meta.query_selector(q_row);
+            let flags = flags.map(|column| meta.query_advice(column, Rotation::cur()));
+            let one = Expression::Constant(F::ONE);
+
+            let sum = flags
+                .iter()
+                .fold(Expression::Constant(F::ZERO), |acc, flag| {
+                    acc + flag.clone()
+                });
+
+            Constraints::with_selector(
+                q_row,
+                flags
+                    .iter()
+                    .map(|flag| flag.clone() * (one.clone() - flag.clone()))
+                    .chain(std::iter::once(sum - one.clone()))
+                    .collect::<Vec<_>>(),
+            )
+        });
+
+        meta.lookup("vm program", |meta| {
+            let q_row = meta.query_selector(q_row);
+            let pc = meta.query_advice(pc, Rotation::cur());
+            let imm = meta.query_advice(imm, Rotation::cur());
+            let opcode =
+                flags
+                    .iter()
+                    .enumerate()
+                    .fold(Expression::Constant(F::ZERO), |acc, (i, column)| {
+                        acc + meta.query_advice(*column, Rotation::cur())
+                            * Expression::Constant(F::from(i as u64 + 1))
+                    });
+
+            vec![
+                (q_row.clone() * pc, program[0]),
+                (q_row.clone() * opcode, program[1]),
+                (q_row * imm, program[2]),
+            ]
+        });
+
+        let is_zero_expr = is_zero_config.expr();
+        meta.create_gate("vm step", |meta| {
+            let q_step = meta.query_selector(q_step);
+            let flag = |meta: &mut VirtualCells<'_, F>, instr: Instr| {
+                meta.query_advice(flags[instr.index()], Rotation::cur())
+            };
+            let one = Expression::Constant(F::ONE);
+            let zero = Expression::Constant(F::ZERO);
+
+            let pc_cur = meta.query_advice(pc, Rotation::cur());
+            let pc_next = meta.query_advice(pc, Rotation::next());
+            let imm = meta.query_advice(imm, Rotation::cur());
+            let s = stack.map(|column| meta.query_advice(column, Rotation::cur()));
+            let t = stack.map(|column| meta.query_advice(column, Rotation::next()));
+
+            // `t == expected` for every entry, and `pc_next == pc`.
+            let transition = |expected: [Expression<F>; STACK_DEPTH], pc: Expression<F>| {
+                t.iter()
+                    .zip(expected)
+                    .map(|(t, expected)| t.clone() - expected)
+                    .chain(std::iter::once(pc_next.clone() - pc))
+                    .collect::<Vec<_>>()
+            };
+            let push = |v: Expression<F>| -> [Expression<F>; STACK_DEPTH] {
+                std::array::from_fn(|i| if i == 0 { v.clone() } else { s[i - 1].clone() })
+            };
+            let pop = |skip: usize| -> [Expression<F>; STACK_DEPTH] {
+                std::array::from_fn(|i| s.get(i + skip).cloned().unwrap_or_else(|| zero.clone()))
+            };
+            let pop2 = |v: Expression<F>| {
+                let mut stack = pop(1);
+                stack[0] = v;
+                stack
+            };
+            let pc_inc = pc_cur.clone() + one.clone();
+
+            let mut constraints = vec![];
+            let mut add = |flag: Expression<F>, exprs: Vec<Expression<F>>| {
+                constraints.extend(exprs.into_iter().map(|expr| flag.clone() * expr));
+            };
+
+            // Pushes also require the bottom entry to be free, so they can't overflow.
+            let f = flag(meta, Instr::Push(0));
+            add(f.clone(), transition(push(imm.clone()), pc_inc.clone()));
+            add(f, vec![s[STACK_DEPTH - 1].clone()]);
+
+            let f = flag(meta, Instr::Add);
+            add(
+                f,
+                transition(pop2(s[0].clone() + s[1].clone()), pc_inc.clone()),
+            );
+
+            let f = flag(meta, Instr::Mul);
+            add(
+                f,
+                transition(pop2(s[0].clone() * s[1].clone()), pc_inc.clone()),
+            );
+
+            let f = flag(meta, Instr::Sub);
+            add(
+                f,
+                transition(pop2(s[0].clone() - s[1].clone()), pc_inc.clone()),
+            );
+
+            for n in 1..=STACK_DEPTH {
+                let f = flag(meta, Instr::Dup(n));
+                add(
+                    f.clone(),
+                    transition(push(s[n - 1].clone()), pc_inc.clone()),
+                );
+                add(f, vec![s[STACK_DEPTH - 1].clone()]);
+            }
+
+            for n in 1..STACK_DEPTH {
+                let f = flag(meta, Instr::Swap(n));
+                let mut expected = s.clone();
+                expected.swap(0, n);
+                add(f, transition(expected, pc_inc.clone()));
+            }
+
+            let f = flag(meta, Instr::Jumpi(0));
+            let jump_pc = is_zero_expr.clone() * pc_inc + (one - is_zero_expr.clone()) * imm;
+            add(f, transition(pop(1), jump_pc));
+
+            let f = flag(meta, Instr::Halt);
+            add(f, transition(s.clone(), pc_cur));
+
+            Constraints::with_selector(q_step, constraints)
+        });
+
+        meta.create_gate("vm last row", |meta| {
+            let q_last = meta.query_selector(q_last);
+            let halt = meta.query_advice(flags[Instr::Halt.index()], Rotation::cur());
+
+            Constraints::with_selector(q_last, [halt - Expression::Constant(F::ONE)])
+        });
+
+        VmConfig {
+            pc,
+            imm,
+            stack,
+            flags,
+            q_first,
+            q_row,
+            q_step,
+            q_last,
+            program,
+            is_zero_config,
+            instance,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), plonk::Error> {
+        self.load_program(&config, layouter.namespace(|| "load program"))?;
+
+        let is_zero_chip = IsZeroChip::new(config.is_zero_config.clone());
+
+        let output = layouter.assign_region(
+            || "trace",
+            |mut region| {
+                config.q_first.enable(&mut region, 0)?;
+                config.q_last.enable(&mut region, self.steps)?;
+
+                let mut output = None;
+                for row in 0..=self.steps {
+                    config.q_row.enable(&mut region, row)?;
+                    if row < self.steps {
+                        config.q_step.enable(&mut region, row)?;
+                    }
+
+                    let state = self.trace.as_ref().map(|trace| trace[row]);
+                    let instr = state.map(|state| self.program[state.pc]);
+
+                    region.assign_advice(
+                        || "pc",
+                        config.pc,
+                        row,
+                        || state.map(|state| F::from(state.pc as u64)),
+                    )?;
+                    region.assign_advice(|| "imm", config.imm, row, || instr.map(|i| i.imm()))?;
+
+                    for (i, column) in config.flags.iter().enumerate() {
+                        let flag = instr.map(|instr| F::from((instr.index() == i) as u64));
+                        region.assign_advice(|| "flag", *column, row, || flag)?;
+                    }
+
+                    for (i, column) in config.stack.iter().enumerate() {
+                        let cell = region.assign_advice(
+                            || format!("s_{}", i),
+                            *column,
+                            row,
+                            || state.map(|state| state.stack[i]),
+                        )?;
+                        if i == 0 {
+                            output = Some(cell);
+                        }
+                    }
+
+                    is_zero_chip.assign(&mut region, row, state.map(|state| state.stack[0]))?;
+                }
+
+                Ok(output.unwrap())
+            },
+        )?;
+
+        layouter.constrain_instance(output.cell(), config.instance, 0)
+    }
+}
@@ -0,0 +1,94 @@
+use fibonacci::{
+    aggregation::{decide, Aggregate, AggregateVerifier, Aggregator, K},
+    fibo::fib1::FiboCircuit,
+    prover::setup,
+};
+use group::{Curve, Group};
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::Value,
+    halo2curves::pasta::{Eq, Fp},
+    plonk::keygen_vk,
+};
+use rand_core::OsRng;
+
+fn inputs(n: u64) -> Vec<(Fp, Fp)> {
+    (0..n).map(|i| (Fp::from(i), Fp::from(i + 1))).collect()
+}
+
+#[test]
+fn aggregate_accepts() {
+    let aggregator = Aggregator::new().unwrap();
+    let aggregate = aggregator.aggregate(&inputs(16), OsRng).unwrap();
+
+    for n in 2..=16 {
+        let prefix = Aggregate {
+            outputs: aggregate.outputs[..n].to_vec(),
+            proofs: aggregate.proofs[..n].to_vec(),
+            accumulators: aggregate.accumulators[..n].to_vec(),
+        };
+
+        aggregator.verifier().verify(&prefix).unwrap();
+    }
+}
+
+#[test]
+fn aggregate_verifier_without_proving_key() {
+    let aggregate = Aggregator::new()
+        .unwrap()
+        .aggregate(&inputs(4), OsRng)
+        .unwrap();
+
+    let params = setup(K);
+    let vk = keygen_vk(
+        &params,
+        &FiboCircuit::new(Value::unknown(), Value::unknown()),
+    )
+    .unwrap();
+    AggregateVerifier::new(&params, &vk)
+        .verify(&aggregate)
+        .unwrap();
+}
+
+#[test]
+fn aggregate_rejects_wrong_output() {
+    let aggregator = Aggregator::new().unwrap();
+    let mut aggregate = aggregator.aggregate(&inputs(4), OsRng).unwrap();
+
+    aggregate.outputs[2] += Fp::ONE;
+    assert!(aggregator.verifier().verify(&aggregate).is_err());
+}
+
+#[test]
+fn aggregate_rejects_swapped_proofs() {
+    let aggregator = Aggregator::new().unwrap();
+    let mut aggregate = aggregator.aggregate(&inputs(4), OsRng).unwrap();
+
+    aggregate.proofs.swap(0, 3);
+    assert!(aggregator.verifier().verify(&aggregate).is_err());
+}
+
+#[test]
+fn aggregate_rejects_wrong_accumulator() {
+    let aggregator = Aggregator::new().unwrap();
+    let aggregate = aggregator.aggregate(&inputs(4), OsRng).unwrap();
+
+    let params = setup(K);
+    assert!(decide(&params, &aggregate.accumulators));
+
+    // A `G` that isn't `<s(u), params.g>`.
+    let mut wrong = Aggregate {
+        outputs: aggregate.outputs.clone(),
+        proofs: aggregate.proofs.clone(),
+        accumulators: aggregate.accumulators.clone(),
+    };
+    wrong.accumulators[1].g = (Eq::from(wrong.accumulators[1].g) + Eq::generator()).to_affine();
+    assert!(!decide(&params, &wrong.accumulators));
+    assert!(aggregator.verifier().verify(&wrong).is_err());
+
+    // The accumulators of other proofs.
+    let mut wrong = aggregate;
+    wrong.accumulators.swap(0, 3);
+    assert!(decide(&params, &wrong.accumulators));
+    assert!(aggregator.verifier().verify(&wrong).is_err());
+}
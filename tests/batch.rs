@@ -0,0 +1,54 @@
+use fibonacci::{
+    fibo::{fib1::FiboCircuit, fibonacci},
+    prover::{batch_verify, keygen, prove, setup, BatchItem},
+    range_check::example2::RangeCheckCircuit,
+};
+use halo2_proofs::{arithmetic::Field, circuit::Value, halo2curves::pasta::Fp};
+use rand_core::OsRng;
+
+#[test]
+fn fibo_batch() {
+    let params = setup(4);
+    let pk = keygen(
+        &params,
+        &FiboCircuit::new(Value::unknown(), Value::unknown()),
+    )
+    .unwrap();
+
+    let mut items: Vec<BatchItem> = (0..12u64)
+        .map(|i| {
+            let (a, b) = (Fp::from(i), Fp::from(i + 1));
+            let output = fibonacci(a, b, 11);
+            let circuit = FiboCircuit::new(Value::known(a), Value::known(b));
+            let proof = prove(&params, &pk, circuit, &[&[output]], OsRng).unwrap();
+            (proof, vec![vec![output]])
+        })
+        .collect();
+
+    assert_eq!(batch_verify(&params, pk.get_vk(), &items), Ok(()));
+    assert_eq!(batch_verify(&params, pk.get_vk(), &[]), Ok(()));
+
+    // A wrong output, and a proof of another statement.
+    items[3].1[0][0] += Fp::ONE;
+    items[10].0 = items[0].0.clone();
+    assert_eq!(batch_verify(&params, pk.get_vk(), &items), Err(vec![3, 10]));
+}
+
+#[test]
+fn range_check_batch() {
+    let params = setup(5);
+    let pk = keygen(&params, &RangeCheckCircuit::<_, 16>::new(Value::unknown())).unwrap();
+
+    let mut items: Vec<BatchItem> = (0..16u64)
+        .map(|value| {
+            let circuit = RangeCheckCircuit::<_, 16>::new(Value::known(Fp::from(value)));
+            (prove(&params, &pk, circuit, &[], OsRng).unwrap(), vec![])
+        })
+        .collect();
+
+    assert_eq!(batch_verify(&params, pk.get_vk(), &items), Ok(()));
+
+    // A truncated proof fails to parse rather than failing the final check.
+    items[15].0.truncate(32);
+    assert_eq!(batch_verify(&params, pk.get_vk(), &items), Err(vec![15]));
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "evm")]
+
+use fibonacci::{
+    evm::{calldata, keygen, prove, render_verifier, setup, verify_in_evm},
+    fibo::{fib1::FiboCircuit, fibonacci},
+    is_zero2::example::FooCircuit,
+};
+use halo2_proofs::{arithmetic::Field, circuit::Value, halo2curves::bn256::Fr};
+use rand_core::OsRng;
+
+fn fibo_calldata(output: Fr) -> (String, Vec<u8>) {
+    let params = setup(4, OsRng);
+    let pk = keygen(
+        &params,
+        &FiboCircuit::new(Value::unknown(), Value::unknown()),
+    )
+    .unwrap();
+
+    let (a, b) = (Fr::from(0), Fr::from(1));
+    let circuit = FiboCircuit::new(Value::known(a), Value::known(b));
+    let proof = prove(&params, &pk, circuit, &[fibonacci(a, b, 11)], OsRng).unwrap();
+
+    let verifier = render_verifier(&params, pk.get_vk(), 1);
+    (verifier, calldata(&proof, &[output]))
+}
+
+#[test]
+fn fibo_verifier_accepts() {
+    let (verifier, calldata) = fibo_calldata(Fr::from(89));
+    assert!(verify_in_evm(&verifier, calldata).is_some());
+}
+
+#[test]
+fn fibo_verifier_rejects_wrong_output() {
+    let (verifier, calldata) = fibo_calldata(Fr::from(89) + Fr::ONE);
+    assert_eq!(verify_in_evm(&verifier, calldata), None);
+}
+
+#[test]
+fn is_zero2_verifier_accepts() {
+    let params = setup(4, OsRng);
+    let pk = keygen(
+        &params,
+        &FooCircuit::new(Value::unknown(), Value::unknown(), Value::unknown()),
+    )
+    .unwrap();
+
+    let circuit = FooCircuit::new(
+        Value::known(Fr::from(11)),
+        Value::known(Fr::from(7)),
+        Value::known(Fr::from(222)),
+    );
+    let proof = prove(&params, &pk, circuit, &[Fr::from(4)], OsRng).unwrap();
+
+    let verifier = render_verifier(&params, pk.get_vk(), 1);
+    assert!(verify_in_evm(&verifier, calldata(&proof, &[Fr::from(4)])).is_some());
+}
@@ -0,0 +1,91 @@
+use std::{env, fs, path::Path};
+
+use fibonacci::{
+    fibo::{fib1, fib2, fib3},
+    prover::{keygen, prove_seeded, setup, verify},
+};
+use halo2_proofs::{circuit::Value, halo2curves::pasta::Fp, plonk::Circuit};
+
+const GOLDEN_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden");
+const SEED: u64 = 42;
+
+// Proofs are compared against `tests/golden/<name>.proof`, which are only written when
+// `UPDATE_GOLDEN` is set, e.g. after an intended change to a circuit. A missing file fails.
+fn check_golden<C: Circuit<Fp>>(name: &str, k: u32, circuit: impl Fn() -> C, instances: &[&[Fp]]) {
+    let params = setup(k);
+    let pk = keygen(&params, &circuit().without_witnesses()).unwrap();
+
+    let proof = prove_seeded(&params, &pk, circuit(), instances, SEED).unwrap();
+    verify(&params, pk.get_vk(), &proof, instances).unwrap();
+
+    assert_eq!(
+        proof,
+        prove_seeded(&params, &pk, circuit(), instances, SEED).unwrap(),
+        "{} isn't reproducible",
+        name
+    );
+    assert_ne!(
+        proof,
+        prove_seeded(&params, &pk, circuit(), instances, SEED + 1).unwrap(),
+        "{} ignores the seed",
+        name
+    );
+
+    let path = Path::new(GOLDEN_DIR).join(format!("{}.proof", name));
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::create_dir_all(GOLDEN_DIR).unwrap();
+        fs::write(&path, &proof).unwrap();
+        return;
+    }
+
+    let golden = fs::read(&path).unwrap_or_else(|err| {
+        panic!(
+            "can't read {}: {}, run with UPDATE_GOLDEN=1 to create it",
+            path.display(),
+            err
+        )
+    });
+    assert!(
+        golden == proof,
+        "{} doesn't match {}, rerun with UPDATE_GOLDEN=1 if the change is intended",
+        name,
+        path.display()
+    );
+}
+
+fn inputs() -> (Value<Fp>, Value<Fp>) {
+    (Value::known(Fp::from(0)), Value::known(Fp::from(1)))
+}
+
+#[test]
+fn fib1() {
+    let (a, b) = inputs();
+    check_golden(
+        "fib1",
+        4,
+        || fib1::FiboCircuit::new(a, b),
+        &[&[Fp::from(89)]],
+    );
+}
+
+#[test]
+fn fib2() {
+    let (a, b) = inputs();
+    check_golden(
+        "fib2",
+        6,
+        || fib2::FiboCircuit::new(a, b),
+        &[&[Fp::from(89)]],
+    );
+}
+
+#[test]
+fn fib3() {
+    let (a, b) = inputs();
+    check_golden(
+        "fib3",
+        4,
+        || fib3::FiboCircuit::new(a, b),
+        &[&[Fp::from(34)]],
+    );
+}
@@ -0,0 +1,51 @@
+use fibonacci::{
+    fibo::{fib1, fib2, fib3},
+    is_zero, is_zero2,
+    prover::{keygen, prove, setup, verify},
+    range_check::{example1, example2},
+};
+use halo2_proofs::{circuit::Value, halo2curves::pasta::Fp, plonk::Circuit};
+use rand_core::OsRng;
+
+// Keys are generated from `without_witnesses()`, as a verifier would, and must still accept a
+// proof of the witnessed circuit.
+fn keygen_without_witnesses<C: Circuit<Fp>>(k: u32, circuit: C, instances: &[&[Fp]]) {
+    let params = setup(k);
+    let pk = keygen(&params, &circuit.without_witnesses()).unwrap();
+    let proof = prove(&params, &pk, circuit, instances, OsRng).unwrap();
+    verify(&params, pk.get_vk(), &proof, instances).unwrap();
+}
+
+#[test]
+fn fibo() {
+    let (a, b) = (Value::known(Fp::from(0)), Value::known(Fp::from(1)));
+
+    keygen_without_witnesses(4, fib1::FiboCircuit::new(a, b), &[&[Fp::from(89)]]);
+    keygen_without_witnesses(6, fib2::FiboCircuit::new(a, b), &[&[Fp::from(89)]]);
+    keygen_without_witnesses(4, fib3::FiboCircuit::new(a, b), &[&[Fp::from(34)]]);
+}
+
+#[test]
+fn is_zero() {
+    let (a, b) = (Value::known(Fp::from(11)), Value::known(Fp::from(11)));
+
+    let circuit = is_zero::example::FooCircuit::new(a, b, Value::known(Fp::from(22)));
+    keygen_without_witnesses(4, circuit, &[]);
+
+    let circuit = is_zero2::example::FooCircuit::new(a, b, Value::known(Fp::from(222)));
+    keygen_without_witnesses(4, circuit, &[&[Fp::from(222)]]);
+}
+
+#[test]
+fn range_check() {
+    let circuit = example1::RangeCheckCircuit::<_, 8>::new(Value::known(Fp::from(7)));
+    keygen_without_witnesses(4, circuit, &[]);
+
+    let circuit = example2::RangeCheckCircuit::<_, 16>::new(Value::known(Fp::from(15)));
+    keygen_without_witnesses(5, circuit, &[]);
+
+    // Just above `LOOKUP_THRESHOLD`, as the expression is configured for every range and its
+    // degree grows with it.
+    let circuit = example2::RangeCheckCircuit::<_, 257>::new(Value::known(Fp::from(256)));
+    keygen_without_witnesses(9, circuit, &[]);
+}
@@ -0,0 +1,337 @@
+use ff::PrimeField;
+use fibonacci::{
+    fe_to_u64,
+    fibo::{fib1, fib2, fib3, fibonacci},
+    is_zero::{IsZeroChip, IsZeroConfig},
+    is_zero2::{example::FooCircuit, IsZero2Chip, IsZero2Config},
+    range_check::{example1, example2},
+};
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    halo2curves::pasta::Fp,
+    plonk::{self, Advice, Circuit, Column, ConstraintSystem, Constraints, Selector},
+    poly::Rotation,
+};
+use proptest::prelude::*;
+
+// Any field element, with zero and small values weighted in since they're the edge cases.
+fn field() -> impl Strategy<Value = Fp> {
+    prop_oneof![
+        Just(Fp::ZERO),
+        any::<u64>().prop_map(Fp::from),
+        any::<[u64; 4]>().prop_map(Fp::from_raw),
+    ]
+}
+
+// `value < bound`, comparing as integers.
+fn is_below(value: Fp, bound: u64) -> bool {
+    value.to_repr()[8..].iter().all(|byte| *byte == 0) && fe_to_u64(&value) < bound
+}
+
+fn is_satisfied<C: Circuit<Fp>>(k: u32, circuit: &C, instances: Vec<Vec<Fp>>) -> bool {
+    MockProver::run(k, circuit, instances)
+        .unwrap()
+        .verify()
+        .is_ok()
+}
+
+// `output = (value == 0)`, with `output` a free witness so wrong outputs can be tried.
+#[derive(Clone)]
+struct IsZeroTestConfig {
+    value: Column<Advice>,
+    output: Column<Advice>,
+    sel: Selector,
+    is_zero_config: IsZeroConfig<Fp>,
+}
+
+#[derive(Default)]
+struct IsZeroCircuit {
+    value: Value<Fp>,
+    output: Value<Fp>,
+}
+
+impl Circuit<Fp> for IsZeroCircuit {
+    type Config = IsZeroTestConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let value = meta.advice_column();
+        let output = meta.advice_column();
+        let sel = meta.selector();
+
+        let is_zero_config = IsZeroChip::configure(
+            meta,
+            |meta| meta.query_selector(sel),
+            |meta| meta.query_advice(value, Rotation::cur()),
+        );
+
+        meta.create_gate("is zero output", |meta| {
+            let sel = meta.query_selector(sel);
+            let output = meta.query_advice(output, Rotation::cur());
+
+            Constraints::with_selector(sel, [output - is_zero_config.expr()])
+        });
+
+        IsZeroTestConfig {
+            value,
+            output,
+            sel,
+            is_zero_config,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), plonk::Error> {
+        let is_zero_chip = IsZeroChip::new(config.is_zero_config.clone());
+
+        layouter.assign_region(
+            || "is zero",
+            |mut region| {
+                config.sel.enable(&mut region, 0)?;
+                region.assign_advice(|| "value", config.value, 0, || self.value)?;
+                region.assign_advice(|| "output", config.output, 0, || self.output)?;
+                is_zero_chip.assign(&mut region, 0, self.value)
+            },
+        )
+    }
+}
+
+// As `IsZeroCircuit`, with `value_inv` a free witness too since `IsZero2Chip` takes it.
+#[derive(Clone)]
+struct IsZero2TestConfig {
+    value: Column<Advice>,
+    output: Column<Advice>,
+    sel: Selector,
+    is_zero_config: IsZero2Config<Fp>,
+}
+
+#[derive(Default)]
+struct IsZero2Circuit {
+    value: Value<Fp>,
+    value_inv: Value<Fp>,
+    output: Value<Fp>,
+}
+
+impl Circuit<Fp> for IsZero2Circuit {
+    type Config = IsZero2TestConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let value = meta.advice_column();
+        let output = meta.advice_column();
+        let sel = meta.selector();
+
+        let is_zero_config = IsZero2Chip::configure(
+            meta,
+            |meta| meta.query_selector(sel),
+            |meta| meta.query_advice(value, Rotation::cur()),
+        );
+
+        meta.create_gate("is zero output", |meta| {
+            let sel = meta.query_selector(sel);
+            let output = meta.query_advice(output, Rotation::cur());
+
+            Constraints::with_selector(sel, [output - is_zero_config.expr().clone()])
+        });
+
+        IsZero2TestConfig {
+            value,
+            output,
+            sel,
+            is_zero_config,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), plonk::Error> {
+        let is_zero_chip = IsZero2Chip::new(config.is_zero_config.clone());
+
+        layouter.assign_region(
+            || "is zero",
+            |mut region| {
+                config.sel.enable(&mut region, 0)?;
+                region.assign_advice(|| "value", config.value, 0, || self.value)?;
+                region.assign_advice(|| "output", config.output, 0, || self.output)?;
+                is_zero_chip.assign(&mut region, self.value_inv)
+            },
+        )
+    }
+}
+
+fn inverse(value: Fp) -> Fp {
+    value.invert().unwrap_or(Fp::ZERO)
+}
+
+fn is_zero(value: Fp) -> Fp {
+    if value == Fp::ZERO {
+        Fp::ONE
+    } else {
+        Fp::ZERO
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn is_zero_accepts_correct_output(value in field()) {
+        let circuit = IsZeroCircuit {
+            value: Value::known(value),
+            output: Value::known(is_zero(value)),
+        };
+        prop_assert!(is_satisfied(4, &circuit, vec![]));
+    }
+
+    #[test]
+    fn is_zero_rejects_wrong_output(value in field(), output in field()) {
+        prop_assume!(output != is_zero(value));
+
+        let circuit = IsZeroCircuit {
+            value: Value::known(value),
+            output: Value::known(output),
+        };
+        prop_assert!(!is_satisfied(4, &circuit, vec![]));
+    }
+
+    #[test]
+    fn is_zero2_accepts_correct_output(value in field()) {
+        let circuit = IsZero2Circuit {
+            value: Value::known(value),
+            value_inv: Value::known(inverse(value)),
+            output: Value::known(is_zero(value)),
+        };
+        prop_assert!(is_satisfied(4, &circuit, vec![]));
+    }
+
+    #[test]
+    fn is_zero2_rejects_wrong_output(value in field(), value_inv in field(), output in field()) {
+        prop_assume!(output != is_zero(value));
+
+        let circuit = IsZero2Circuit {
+            value: Value::known(value),
+            value_inv: Value::known(value_inv),
+            output: Value::known(output),
+        };
+        prop_assert!(!is_satisfied(4, &circuit, vec![]));
+    }
+
+    #[test]
+    fn foo2_matches_native(a in field(), b in field(), c in field(), equal: bool, wrong in field()) {
+        let b = if equal { a } else { b };
+        let output = if a == b { c } else { a - b };
+        let circuit = FooCircuit::new(Value::known(a), Value::known(b), Value::known(c));
+
+        prop_assert!(is_satisfied(4, &circuit, vec![vec![output]]));
+        if wrong != output {
+            prop_assert!(!is_satisfied(4, &circuit, vec![vec![wrong]]));
+        }
+    }
+
+    #[test]
+    fn range_check1_accepts_in_range(value in 0..8u64) {
+        let circuit = example1::RangeCheckCircuit::<_, 8>::new(Value::known(Fp::from(value)));
+        prop_assert!(is_satisfied(4, &circuit, vec![]));
+    }
+
+    #[test]
+    fn range_check1_rejects_out_of_range(value in field()) {
+        prop_assume!(!is_below(value, 8));
+
+        let circuit = example1::RangeCheckCircuit::<_, 8>::new(Value::known(value));
+        prop_assert!(!is_satisfied(4, &circuit, vec![]));
+    }
+
+    #[test]
+    fn range_check2_expression_accepts_in_range(value in 0..16u64) {
+        let circuit = example2::RangeCheckCircuit::<_, 16>::new(Value::known(Fp::from(value)));
+        prop_assert!(is_satisfied(5, &circuit, vec![]));
+    }
+
+    #[test]
+    fn range_check2_expression_rejects_out_of_range(value in field()) {
+        prop_assume!(!is_below(value, 16));
+
+        let circuit = example2::RangeCheckCircuit::<_, 16>::new(Value::known(value));
+        prop_assert!(!is_satisfied(5, &circuit, vec![]));
+    }
+
+    #[test]
+    fn range_check2_lookup_accepts_in_range(value in 0..1024u64) {
+        let circuit = example2::RangeCheckCircuit::<_, 1024>::new(Value::known(Fp::from(value)));
+        prop_assert!(is_satisfied(11, &circuit, vec![]));
+    }
+
+    #[test]
+    fn range_check2_lookup_rejects_out_of_range(value in field()) {
+        prop_assume!(!is_below(value, 1024));
+
+        let circuit = example2::RangeCheckCircuit::<_, 1024>::new(Value::known(value));
+        prop_assert!(!is_satisfied(11, &circuit, vec![]));
+    }
+
+    #[test]
+    fn fib1_matches_native(a in field(), b in field(), wrong in field()) {
+        let output = fibonacci(a, b, 11);
+        let circuit = fib1::FiboCircuit::new(Value::known(a), Value::known(b));
+
+        prop_assert!(is_satisfied(4, &circuit, vec![vec![output]]));
+        if wrong != output {
+            prop_assert!(!is_satisfied(4, &circuit, vec![vec![wrong]]));
+        }
+    }
+
+    #[test]
+    fn fib2_matches_native(a in field(), b in field(), wrong in field()) {
+        let output = fibonacci(a, b, 11);
+        let circuit = fib2::FiboCircuit::new(Value::known(a), Value::known(b));
+
+        prop_assert!(is_satisfied(6, &circuit, vec![vec![output]]));
+        if wrong != output {
+            prop_assert!(!is_satisfied(6, &circuit, vec![vec![wrong]]));
+        }
+    }
+
+    #[test]
+    fn fib3_matches_native(a in field(), b in field(), wrong in field()) {
+        let output = fibonacci(a, b, 9);
+        let circuit = fib3::FiboCircuit::new(Value::known(a), Value::known(b));
+
+        prop_assert!(is_satisfied(4, &circuit, vec![vec![output]]));
+        if wrong != output {
+            prop_assert!(!is_satisfied(4, &circuit, vec![vec![wrong]]));
+        }
+    }
+}
+
+// `value * (value - 1) * ... * (value - RANGE)` used to accept `RANGE` itself, which a random
+// field element almost never hits.
+#[test]
+fn range_check2_expression_rejects_range() {
+    let circuit = example2::RangeCheckCircuit::<_, 16>::new(Value::known(Fp::from(16)));
+    assert!(!is_satisfied(5, &circuit, vec![]));
+
+    let circuit = example2::RangeCheckCircuit::<_, 16>::new(Value::known(Fp::from(15)));
+    assert!(is_satisfied(5, &circuit, vec![]));
+}
+
+#[test]
+fn fibonacci_reference() {
+    assert_eq!(fibonacci(Fp::ZERO, Fp::ONE, 11), Fp::from(89));
+    assert_eq!(fibonacci(Fp::ZERO, Fp::ONE, 9), Fp::from(34));
+}
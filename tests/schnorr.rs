@@ -0,0 +1,113 @@
+use ff::PrimeField;
+use fibonacci::ecc::{scalar_bits, EccChip, EccConfig};
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    halo2curves::pasta::{pallas, Fp},
+    plonk::{self, Advice, Circuit, Column, ConstraintSystem},
+};
+
+#[derive(Clone)]
+struct ChallengeConfig {
+    ecc_config: EccConfig,
+    advice: Column<Advice>,
+}
+
+// Decomposes the challenge `e` as the Schnorr circuit does, but into the given `bits`.
+#[derive(Default)]
+struct ChallengeCircuit {
+    e: Value<Fp>,
+    bits: Value<Vec<bool>>,
+    canonical: bool,
+}
+
+impl Circuit<Fp> for ChallengeCircuit {
+    type Config = ChallengeConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            canonical: self.canonical,
+            ..Self::default()
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let ecc_config = EccChip::<pallas::Affine>::configure(meta);
+        let advice = meta.advice_column();
+        meta.enable_equality(advice);
+
+        ChallengeConfig { ecc_config, advice }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), plonk::Error> {
+        let chip = EccChip::<pallas::Affine>::new(config.ecc_config);
+
+        let e = layouter.assign_region(
+            || "e",
+            |mut region| region.assign_advice(|| "e", config.advice, 0, || self.e),
+        )?;
+        let bits = chip.decompose_bits(
+            layouter.namespace(|| "decompose e"),
+            &e,
+            self.bits.clone(),
+            Fp::NUM_BITS as usize,
+        )?;
+        if self.canonical {
+            chip.assert_canonical(layouter.namespace(|| "canonical e"), &bits)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn run(
+    e: Fp,
+    bits: Vec<bool>,
+    canonical: bool,
+) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let circuit = ChallengeCircuit {
+        e: Value::known(e),
+        bits: Value::known(bits),
+        canonical,
+    };
+    MockProver::run(10, &circuit, vec![]).unwrap().verify()
+}
+
+// Little-endian bits of `e + p`, which still fit in `Fp::NUM_BITS` bits for a small `e`.
+fn non_canonical_bits(e: Fp) -> Vec<bool> {
+    let mut carry = false;
+    let bits = scalar_bits(&-Fp::ONE)
+        .into_iter()
+        .zip(scalar_bits(&(e + Fp::ONE)))
+        .map(|(a, b)| {
+            let sum = a as u8 + b as u8 + carry as u8;
+            carry = sum > 1;
+            sum & 1 == 1
+        })
+        .collect();
+    assert!(!carry);
+    bits
+}
+
+#[test]
+fn canonical_challenge() {
+    for e in [Fp::ZERO, Fp::from(5), -Fp::ONE] {
+        run(e, scalar_bits(&e), true).unwrap();
+    }
+}
+
+#[test]
+fn non_canonical_challenge() {
+    let e = Fp::from(5);
+    let bits = non_canonical_bits(e);
+
+    // The running sum alone accepts the bits of `e + p`, which make `e * pk` a different point.
+    run(e, bits.clone(), false).unwrap();
+    assert!(run(e, bits, true).is_err());
+}